@@ -0,0 +1,335 @@
+use chrono::NaiveDateTime;
+use derive_builder::Builder;
+use serde_derive::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::core::deserialize_td_datetime;
+use crate::fundamentals::{FundamentalsMeta, SymbolRequest};
+
+pub type AnalysisRequest = SymbolRequest;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum EstimatePeriod {
+    #[serde(rename = "current_quarter")]
+    CurrentQuarter,
+
+    #[serde(rename = "next_quarter")]
+    NextQuarter,
+
+    #[serde(rename = "current_year")]
+    CurrentYear,
+
+    #[serde(rename = "next_year")]
+    NextYear,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EarningsEstimateResponse {
+    pub meta: FundamentalsMeta,
+    pub earnings_estimate: Vec<EarningsEstimate>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EarningsEstimate {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub date: NaiveDateTime,
+    pub period: EstimatePeriod,
+
+    #[serde(default)]
+    pub number_of_analysts: Option<u32>,
+    #[serde(default)]
+    pub avg_estimate: Option<f64>,
+    #[serde(default)]
+    pub low_estimate: Option<f64>,
+    #[serde(default)]
+    pub high_estimate: Option<f64>,
+    #[serde(default)]
+    pub year_ago_eps: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RevenueEstimateResponse {
+    pub meta: FundamentalsMeta,
+    pub revenue_estimate: Vec<RevenueEstimate>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RevenueEstimate {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub date: NaiveDateTime,
+    pub period: EstimatePeriod,
+
+    #[serde(default)]
+    pub number_of_analysts: Option<u32>,
+    #[serde(default)]
+    pub avg_estimate: Option<f64>,
+    #[serde(default)]
+    pub low_estimate: Option<f64>,
+    #[serde(default)]
+    pub high_estimate: Option<f64>,
+    #[serde(default)]
+    pub year_ago_sales: Option<f64>,
+    #[serde(default)]
+    pub sales_growth: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EpsTrendResponse {
+    pub meta: FundamentalsMeta,
+    pub eps_trend: Vec<EpsTrend>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EpsTrend {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub date: NaiveDateTime,
+    pub period: EstimatePeriod,
+
+    #[serde(default)]
+    pub current_estimate: Option<f64>,
+    #[serde(rename = "7_days_ago", default)]
+    pub seven_days_ago: Option<f64>,
+    #[serde(rename = "30_days_ago", default)]
+    pub thirty_days_ago: Option<f64>,
+    #[serde(rename = "60_days_ago", default)]
+    pub sixty_days_ago: Option<f64>,
+    #[serde(rename = "90_days_ago", default)]
+    pub ninety_days_ago: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EpsRevisionsResponse {
+    pub meta: FundamentalsMeta,
+    pub eps_revision: Vec<EpsRevision>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EpsRevision {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub date: NaiveDateTime,
+    pub period: EstimatePeriod,
+
+    #[serde(default)]
+    pub up_last_week: Option<u32>,
+    #[serde(default)]
+    pub up_last_month: Option<u32>,
+    #[serde(default)]
+    pub down_last_week: Option<u32>,
+    #[serde(default)]
+    pub down_last_month: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GrowthEstimatesResponse {
+    pub meta: FundamentalsMeta,
+    pub growth_estimates: GrowthEstimates,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct GrowthEstimates {
+    pub current_quarter: Option<f64>,
+    pub next_quarter: Option<f64>,
+    pub current_year: Option<f64>,
+    pub next_year: Option<f64>,
+
+    #[serde(rename = "next_5_years_pa")]
+    pub next_five_years_pa: Option<f64>,
+
+    #[serde(rename = "past_5_years_pa")]
+    pub past_five_years_pa: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecommendationsResponse {
+    pub meta: FundamentalsMeta,
+    pub trends: RecommendationTrends,
+
+    #[serde(default)]
+    pub rating: Option<f64>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecommendationTrends {
+    pub current_month: Recommendations,
+    pub previous_month: Recommendations,
+
+    #[serde(rename = "2_months_ago")]
+    pub two_months_ago: Recommendations,
+
+    #[serde(rename = "3_months_ago")]
+    pub three_months_ago: Recommendations,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Recommendations {
+    pub strong_buy: u32,
+    pub buy: u32,
+    pub hold: u32,
+    pub sell: u32,
+    pub strong_sell: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PriceTargetResponse {
+    pub meta: FundamentalsMeta,
+    pub price_target: PriceTarget,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PriceTarget {
+    #[serde(default)]
+    pub high: Option<f64>,
+    #[serde(default)]
+    pub median: Option<f64>,
+    #[serde(default)]
+    pub low: Option<f64>,
+    #[serde(default)]
+    pub average: Option<f64>,
+    #[serde(default)]
+    pub current: Option<f64>,
+    pub currency: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct AnalystRatingsRequest {
+    #[serde(flatten)]
+    pub instrument: SymbolRequest,
+
+    #[builder(default, setter(strip_option))]
+    pub rating_change: Option<RatingChangeFilter>,
+
+    #[serde(rename = "outputsize")]
+    #[builder(default, setter(strip_option))]
+    pub output_size: Option<u16>,
+}
+
+impl AnalystRatingsRequest {
+    pub fn builder() -> AnalystRatingsRequestBuilder {
+        AnalystRatingsRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum RatingChangeFilter {
+    Maintains,
+    Upgrade,
+    Downgrade,
+    Initiates,
+    Reiterates,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum RatingChange {
+    Maintains,
+    Upgrade,
+    Downgrade,
+    Initiates,
+    Reiterates,
+
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnalystRatingsResponse {
+    pub meta: FundamentalsMeta,
+    pub ratings: Vec<AnalystRating>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnalystRating {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub date: NaiveDateTime,
+    pub firm: String,
+    pub rating_change: RatingChange,
+    pub rating_current: String,
+
+    #[serde(default)]
+    pub rating_prior: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use tokio_test::assert_ok;
+
+    use super::*;
+
+    #[test]
+    pub fn test_eps_trend_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York"},"eps_trend":[{"date":"2022-09-30","period":"current_quarter","current_estimate":1.26,"7_days_ago":1.26,"30_days_ago":1.26,"60_days_ago":1.26,"90_days_ago":1.28},{"date":"2023-09-30","period":"next_year","current_estimate":6.51,"7_days_ago":6.5,"30_days_ago":6.5,"60_days_ago":null,"90_days_ago":null}],"status":"ok"}"#;
+
+        let response = serde_json::from_str::<EpsTrendResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert!(matches!(res.eps_trend[1].period, EstimatePeriod::NextYear));
+        assert_eq!(Some(1.28), res.eps_trend[0].ninety_days_ago);
+        assert!(res.eps_trend[1].sixty_days_ago.is_none());
+    }
+
+    #[test]
+    pub fn test_recommendations_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York"},"trends":{"current_month":{"strong_buy":13,"buy":20,"hold":8,"sell":0,"strong_sell":0},"previous_month":{"strong_buy":13,"buy":20,"hold":8,"sell":0,"strong_sell":0},"2_months_ago":{"strong_buy":12,"buy":21,"hold":8,"sell":0,"strong_sell":0},"3_months_ago":{"strong_buy":12,"buy":20,"hold":9,"sell":0,"strong_sell":0}},"rating":8.3,"status":"ok"}"#;
+
+        let response = serde_json::from_str::<RecommendationsResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(12, res.trends.two_months_ago.strong_buy);
+        assert_eq!(Some(8.3), res.rating);
+    }
+
+    #[test]
+    pub fn test_analyst_ratings_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York"},"ratings":[{"date":"2022-10-24","firm":"Tigress Financial","rating_change":"Maintains","rating_current":"Buy","rating_prior":"Buy"},{"date":"2022-10-20","firm":"Wedbush","rating_change":"Initiates","rating_current":"Outperform","rating_prior":null}],"status":"ok"}"#;
+
+        let response = serde_json::from_str::<AnalystRatingsResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert!(matches!(
+            res.ratings[1].rating_change,
+            RatingChange::Initiates
+        ));
+        assert!(res.ratings[1].rating_prior.is_none());
+    }
+
+    #[test]
+    pub fn test_analyst_ratings_request() {
+        let request = AnalystRatingsRequest::builder()
+            .instrument(
+                SymbolRequest::builder()
+                    .symbol("AAPL".into())
+                    .build()
+                    .unwrap(),
+            )
+            .rating_change(RatingChangeFilter::Upgrade)
+            .output_size(10)
+            .build()
+            .unwrap();
+
+        let query = serde_urlencoded::to_string(&request);
+
+        assert_ok!(&query);
+
+        assert_eq!(
+            "symbol=AAPL&rating_change=Upgrade&outputsize=10",
+            query.unwrap()
+        );
+    }
+}
@@ -1,11 +1,17 @@
-use std::ops::Range;
+use std::{fmt::Display, ops::Range};
 
 use chrono::{NaiveDate, NaiveDateTime};
 use derive_builder::Builder;
+use serde::de::DeserializeOwned;
 use serde_derive::{Deserialize, Serialize};
-use serde_with::{serde_as, skip_serializing_none, DisplayFromStr};
+use serde_with::{
+    formats::CommaSeparator, serde_as, skip_serializing_none, DisplayFromStr, PickFirst,
+    StringWithSeparator,
+};
 
 use super::{CommonQueryParameters, Interval, Order};
+use crate::errors::Error;
+use crate::technical::TechnicalIndicator;
 
 #[derive(Debug, Serialize, Deserialize, Builder)]
 #[builder(pattern = "owned")]
@@ -79,6 +85,43 @@ pub struct TimeSeriesQuote {
     pub volume: f64,
 }
 
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct TimeSeriesBatchRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    #[serde(rename = "symbol")]
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
+    pub symbols: Vec<String>,
+    pub interval: Interval,
+
+    #[serde(rename = "outputsize")]
+    #[builder(default, setter(strip_option))]
+    pub output_size: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub order: Option<Order>,
+
+    #[builder(default, setter(strip_option))]
+    pub start_date: Option<NaiveDateTime>,
+
+    #[builder(default, setter(strip_option))]
+    pub end_date: Option<NaiveDateTime>,
+
+    #[builder(default, setter(strip_option))]
+    pub previous_close: Option<bool>,
+}
+
+impl TimeSeriesBatchRequest {
+    pub fn builder() -> TimeSeriesBatchRequestBuilder {
+        TimeSeriesBatchRequestBuilder::default()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Builder)]
 #[builder(pattern = "owned")]
 #[skip_serializing_none]
@@ -101,6 +144,37 @@ pub struct QuoteRequest {
     pub rolling_period: Option<u8>,
 }
 
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct QuotesRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    #[serde(rename = "symbol")]
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
+    pub symbols: Vec<String>,
+    pub interval: Interval,
+
+    #[builder(default, setter(strip_option))]
+    pub volume_time_period: Option<u32>,
+
+    #[serde(rename = "eod")]
+    #[builder(default, setter(strip_option))]
+    pub end_of_day: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub rolling_period: Option<u8>,
+}
+
+impl QuotesRequest {
+    pub fn builder() -> QuotesRequestBuilder {
+        QuotesRequestBuilder::default()
+    }
+}
+
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct QuoteResponse {
@@ -193,6 +267,26 @@ pub struct PriceRequest {
     pub previous_close: Option<bool>,
 }
 
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct PricesRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    #[serde(rename = "symbol")]
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
+    pub symbols: Vec<String>,
+}
+
+impl PricesRequest {
+    pub fn builder() -> PricesRequestBuilder {
+        PricesRequestBuilder::default()
+    }
+}
+
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct PriceResponse {
@@ -200,6 +294,365 @@ pub struct PriceResponse {
     pub price: f64,
 }
 
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct EodRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    pub symbol: String,
+
+    #[builder(default, setter(strip_option))]
+    pub date: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub prepost: Option<bool>,
+}
+
+impl EodRequest {
+    pub fn builder() -> EodRequestBuilder {
+        EodRequestBuilder::default()
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EodResponse {
+    pub symbol: String,
+    pub exchange: String,
+    pub mic_code: String,
+    pub currency: String,
+    pub timestamp: i64,
+
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub datetime: NaiveDateTime,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub close: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct EarliestTimestampRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    pub symbol: String,
+    pub interval: Interval,
+}
+
+impl EarliestTimestampRequest {
+    pub fn builder() -> EarliestTimestampRequestBuilder {
+        EarliestTimestampRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EarliestTimestampResponse {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub datetime: NaiveDateTime,
+    pub unix_time: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct ExchangeRateRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    pub symbol: String,
+
+    #[builder(default, setter(strip_option))]
+    pub date: Option<NaiveDate>,
+}
+
+impl ExchangeRateRequest {
+    pub fn builder() -> ExchangeRateRequestBuilder {
+        ExchangeRateRequestBuilder::default()
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExchangeRateResponse {
+    pub symbol: String,
+
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub rate: f64,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct CurrencyConversionRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    pub symbol: String,
+    pub amount: f64,
+
+    #[builder(default, setter(strip_option))]
+    pub date: Option<NaiveDate>,
+}
+
+impl CurrencyConversionRequest {
+    pub fn builder() -> CurrencyConversionRequestBuilder {
+        CurrencyConversionRequestBuilder::default()
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CurrencyConversionResponse {
+    pub symbol: String,
+
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub rate: f64,
+
+    /// The requested amount converted to the quote currency.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub amount: f64,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub enum MarketKind {
+    #[default]
+    #[serde(rename = "stocks")]
+    Stocks,
+
+    #[serde(rename = "etf")]
+    Etf,
+
+    #[serde(rename = "mutual_funds")]
+    MutualFunds,
+
+    #[serde(rename = "forex")]
+    Forex,
+
+    #[serde(rename = "crypto")]
+    Crypto,
+}
+
+impl Display for MarketKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                MarketKind::Stocks => "stocks",
+                MarketKind::Etf => "etf",
+                MarketKind::MutualFunds => "mutual_funds",
+                MarketKind::Forex => "forex",
+                MarketKind::Crypto => "crypto",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum MoverDirection {
+    #[serde(rename = "gainers")]
+    Gainers,
+
+    #[serde(rename = "losers")]
+    Losers,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct MarketMoversRequest {
+    #[serde(skip)]
+    pub market: MarketKind,
+
+    #[builder(default, setter(strip_option))]
+    pub direction: Option<MoverDirection>,
+
+    #[serde(rename = "outputsize")]
+    #[builder(default, setter(strip_option))]
+    pub output_size: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub country: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub price_greater_than: Option<f64>,
+
+    #[serde(rename = "dp")]
+    #[builder(default, setter(strip_option))]
+    pub decimal_places: Option<u8>,
+}
+
+impl MarketMoversRequest {
+    pub fn builder() -> MarketMoversRequestBuilder {
+        MarketMoversRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MarketMoversResponse {
+    pub values: Vec<MarketMover>,
+    pub status: String,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MarketMover {
+    pub symbol: String,
+    pub name: String,
+
+    #[serde(default)]
+    pub exchange: Option<String>,
+    #[serde(default)]
+    pub mic_code: Option<String>,
+
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub datetime: NaiveDateTime,
+
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub last: f64,
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub high: f64,
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub low: f64,
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub volume: f64,
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub change: f64,
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub percent_change: f64,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Builder)]
+#[builder(pattern = "owned")]
+pub struct ComplexDataRequest {
+    pub symbols: Vec<String>,
+    pub intervals: Vec<Interval>,
+    pub methods: Vec<ComplexDataMethod>,
+
+    #[serde(rename = "outputsize")]
+    #[builder(default, setter(strip_option))]
+    pub output_size: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub order: Option<Order>,
+
+    #[builder(default, setter(strip_option))]
+    pub start_date: Option<NaiveDateTime>,
+
+    #[builder(default, setter(strip_option))]
+    pub end_date: Option<NaiveDateTime>,
+
+    #[builder(default, setter(strip_option))]
+    pub timezone: Option<String>,
+
+    #[serde(rename = "dp")]
+    #[builder(default, setter(strip_option))]
+    pub decimal_places: Option<u8>,
+}
+
+impl ComplexDataRequest {
+    pub fn builder() -> ComplexDataRequestBuilder {
+        ComplexDataRequestBuilder::default()
+    }
+}
+
+/// A method of the complex data request, either the time series itself or
+/// one of the technical indicators with its parameters.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct ComplexDataMethod(serde_json::Value);
+
+impl ComplexDataMethod {
+    pub fn time_series() -> Self {
+        Self("time_series".into())
+    }
+
+    pub fn indicator<I: TechnicalIndicator>(indicator: &I) -> crate::errors::Result<Self> {
+        let mut descriptor = serde_json::Map::new();
+        descriptor.insert("name".into(), I::ENDPOINT.into());
+
+        match serde_json::to_value(indicator)? {
+            serde_json::Value::Object(parameters) => {
+                descriptor.extend(parameters.into_iter().filter(|(_, value)| !value.is_null()))
+            }
+            serde_json::Value::Null => {}
+            _ => {
+                return Err(Error::DataError(format!(
+                    "parameters of the {} indicator are not an object",
+                    I::ENDPOINT
+                )))
+            }
+        }
+
+        Ok(Self(serde_json::Value::Object(descriptor)))
+    }
+}
+
+/// A single series of the complex data response. The values are kept as
+/// returned and are parsed on demand, since their shape depends on the method.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComplexDataSeries {
+    pub meta: ComplexDataMeta,
+    pub status: String,
+
+    #[serde(default)]
+    values: Vec<serde_json::Value>,
+}
+
+impl ComplexDataSeries {
+    pub fn time_series(&self) -> crate::errors::Result<Vec<TimeSeriesQuote>> {
+        self.parse_values()
+    }
+
+    pub fn indicator<I: TechnicalIndicator>(&self) -> crate::errors::Result<Vec<I::Value>> {
+        self.parse_values()
+    }
+
+    fn parse_values<V: DeserializeOwned>(&self) -> crate::errors::Result<Vec<V>> {
+        self.values
+            .iter()
+            .map(|value| Ok(serde_json::from_value(value.clone())?))
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComplexDataMeta {
+    pub symbol: String,
+    pub interval: Interval,
+
+    #[serde(default)]
+    pub currency: Option<String>,
+    pub exchange_timezone: String,
+    pub exchange: String,
+    pub mic_code: String,
+
+    #[serde(rename = "type")]
+    pub instrument_type: String,
+
+    #[serde(default)]
+    pub indicator: Option<ComplexDataIndicator>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComplexDataIndicator {
+    pub name: String,
+
+    #[serde(flatten)]
+    pub parameters: serde_json::Map<String, serde_json::Value>,
+}
+
 pub fn deserialize_td_datetime<'de, D>(d: D) -> Result<NaiveDateTime, D::Error>
 where
     D: serde::de::Deserializer<'de>,
@@ -284,6 +737,7 @@ mod test {
     use tokio_test::assert_ok;
 
     use super::*;
+    use crate::technical::{Ema, SeriesType, Sma};
 
     #[test]
     pub fn test_timeseries_response() {
@@ -310,4 +764,168 @@ mod test {
         assert_eq!(range.start, 129.039993);
         assert_eq!(range.end, 182.940002);
     }
+
+    #[test]
+    pub fn test_earliest_timestamp_response() {
+        let response = r#"{"datetime":"1980-12-12 09:30:00","unix_time":345479400}"#;
+
+        let response = serde_json::from_str::<EarliestTimestampResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(
+            NaiveDate::from_ymd_opt(1980, 12, 12)
+                .unwrap()
+                .and_hms_opt(9, 30, 0)
+                .unwrap(),
+            res.datetime
+        );
+        assert_eq!(345479400, res.unix_time);
+    }
+
+    #[test]
+    pub fn test_currency_conversion_response() {
+        let response =
+            r#"{"symbol":"USD/JPY","rate":105.12,"amount":12315.7,"timestamp":1602714051}"#;
+
+        let response = serde_json::from_str::<CurrencyConversionResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(105.12, res.rate);
+        assert_eq!(12315.7, res.amount);
+    }
+
+    #[test]
+    pub fn test_exchange_rate_response() {
+        let response = r#"{"symbol":"USD/JPY","rate":105.12,"timestamp":1602714051}"#;
+
+        let response = serde_json::from_str::<ExchangeRateResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!("USD/JPY", res.symbol);
+        assert_eq!(105.12, res.rate);
+        assert_eq!(1602714051, res.timestamp);
+
+        let response = r#"{"symbol":"USD/JPY","rate":"105.12","timestamp":1602714051}"#;
+
+        let response = serde_json::from_str::<ExchangeRateResponse>(response);
+
+        assert_ok!(&response);
+        assert_eq!(105.12, response.unwrap().rate);
+    }
+
+    #[test]
+    pub fn test_eod_response() {
+        let response = r#"{"symbol":"AAPL","exchange":"NASDAQ","mic_code":"XNAS","currency":"USD","datetime":"2021-09-16","timestamp":1631772000,"close":"148.79000"}"#;
+
+        let response = serde_json::from_str::<EodResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2021, 9, 16)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+            res.datetime
+        );
+        assert_eq!(148.79, res.close);
+    }
+
+    #[test]
+    pub fn test_quotes_request() {
+        let request = QuotesRequest::builder()
+            .symbols(vec!["AAPL".into(), "MSFT".into()])
+            .interval(Interval::Day)
+            .build()
+            .unwrap();
+
+        let query = serde_urlencoded::to_string(&request);
+
+        assert_ok!(&query);
+
+        assert_eq!("symbol=AAPL%2CMSFT&interval=1day", query.unwrap());
+    }
+
+    #[test]
+    pub fn test_complex_data_response() {
+        let response = r#"[{"meta":{"symbol":"AAPL","interval":"1day","currency":"USD","exchange_timezone":"America/New_York","exchange":"NASDAQ","mic_code":"XNGS","type":"Common Stock"},"values":[{"datetime":"2022-09-20","open":"153.39999","high":"158.08000","low":"153.08000","close":"156.89999","volume":"107547900"}],"status":"ok"},{"meta":{"symbol":"AAPL","interval":"1day","currency":"USD","exchange_timezone":"America/New_York","exchange":"NASDAQ","mic_code":"XNGS","type":"Common Stock","indicator":{"name":"EMA - Exponential Moving Average","series_type":"close","time_period":12}},"values":[{"datetime":"2022-09-20","ema":"155.43514"}],"status":"ok"}]"#;
+
+        let response = serde_json::from_str::<Vec<ComplexDataSeries>>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        let time_series = res[0].time_series();
+        assert_ok!(&time_series);
+        assert_eq!(156.89999, time_series.unwrap()[0].close);
+        assert_eq!(
+            "EMA - Exponential Moving Average",
+            res[1].meta.indicator.as_ref().unwrap().name
+        );
+        let ema = res[1].indicator::<Ema>();
+        assert_ok!(&ema);
+        assert_eq!(155.43514, ema.unwrap()[0].ema);
+        assert!(res[1].time_series().is_err());
+    }
+
+    #[test]
+    pub fn test_complex_data_request() {
+        let ema = Ema::builder()
+            .series_type(SeriesType::Close)
+            .time_period(12)
+            .build()
+            .unwrap();
+
+        let request = ComplexDataRequest::builder()
+            .symbols(vec!["AAPL".into()])
+            .intervals(vec![Interval::Day])
+            .methods(vec![
+                ComplexDataMethod::time_series(),
+                ComplexDataMethod::indicator(&ema).unwrap(),
+                ComplexDataMethod::indicator(&Sma::default()).unwrap(),
+            ])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            r#"{"symbols":["AAPL"],"intervals":["1day"],"methods":["time_series",{"name":"ema","series_type":"close","time_period":12},{"name":"sma"}]}"#,
+            serde_json::to_string(&request).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_market_movers_response() {
+        let response = r#"{"values":[{"symbol":"BSET","name":"Bassett Furniture Industries Inc","exchange":"NASDAQ","mic_code":"XNMS","datetime":"2023-10-01 12:00:00","last":17.25,"high":18,"low":16.5,"volume":108297,"change":3.31,"percent_change":23.74}],"status":"ok"}"#;
+
+        let response = serde_json::from_str::<MarketMoversResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(23.74, res.values[0].percent_change);
+    }
+
+    #[test]
+    pub fn test_market_movers_request() {
+        let request = MarketMoversRequest::builder()
+            .market(MarketKind::Crypto)
+            .direction(MoverDirection::Losers)
+            .output_size(5)
+            .build()
+            .unwrap();
+
+        let query = serde_urlencoded::to_string(&request);
+
+        assert_ok!(&query);
+
+        assert_eq!("direction=losers&outputsize=5", query.unwrap());
+        assert_eq!("crypto", request.market.to_string());
+    }
 }
@@ -1,6 +1,12 @@
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDate, NaiveDateTime};
 use derive_builder::Builder;
 use serde_derive::{Deserialize, Serialize};
-use serde_with::skip_serializing_none;
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr, PickFirst};
+
+use crate::core::deserialize_td_datetime;
+use crate::CommonQueryParameters;
 
 #[derive(Debug, Serialize, Deserialize, Builder)]
 #[builder(pattern = "owned")]
@@ -14,5 +20,1375 @@ pub struct LogoRequest {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LogoResponse {
-    url: String,
+    pub url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct SymbolRequest {
+    pub symbol: String,
+
+    #[builder(default, setter(strip_option))]
+    pub exchange: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub mic_code: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub country: Option<String>,
+}
+
+impl SymbolRequest {
+    pub fn builder() -> SymbolRequestBuilder {
+        SymbolRequestBuilder::default()
+    }
+}
+
+pub type ProfileRequest = SymbolRequest;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProfileResponse {
+    pub symbol: String,
+    pub name: String,
+    pub exchange: String,
+    pub mic_code: String,
+
+    #[serde(rename = "type")]
+    pub instrument_type: String,
+
+    #[serde(default)]
+    pub sector: Option<String>,
+    #[serde(default)]
+    pub industry: Option<String>,
+    #[serde(default)]
+    pub employees: Option<u64>,
+    #[serde(default)]
+    pub website: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(rename = "CEO", default)]
+    pub ceo: Option<String>,
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub address2: Option<String>,
+    #[serde(default)]
+    pub city: Option<String>,
+    #[serde(default)]
+    pub zip: Option<String>,
+    #[serde(default)]
+    pub state: Option<String>,
+    #[serde(default)]
+    pub country: Option<String>,
+    #[serde(default)]
+    pub phone: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FundamentalsMeta {
+    pub symbol: String,
+    pub name: String,
+    pub currency: String,
+    pub exchange: String,
+    pub mic_code: String,
+    pub exchange_timezone: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum CorporateActionsRange {
+    #[serde(rename = "last")]
+    Last,
+
+    #[serde(rename = "next")]
+    Next,
+
+    #[serde(rename = "1m")]
+    Month,
+
+    #[serde(rename = "3m")]
+    ThreeMonths,
+
+    #[serde(rename = "6m")]
+    SixMonths,
+
+    #[serde(rename = "ytd")]
+    YearToDate,
+
+    #[serde(rename = "1y")]
+    Year,
+
+    #[serde(rename = "2y")]
+    TwoYears,
+
+    #[serde(rename = "5y")]
+    FiveYears,
+
+    #[serde(rename = "full")]
+    Full,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct DividendsRequest {
+    #[serde(flatten)]
+    pub instrument: SymbolRequest,
+
+    #[builder(default, setter(strip_option))]
+    pub range: Option<CorporateActionsRange>,
+
+    #[builder(default, setter(strip_option))]
+    pub start_date: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub end_date: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub adjust: Option<bool>,
+}
+
+impl DividendsRequest {
+    pub fn builder() -> DividendsRequestBuilder {
+        DividendsRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DividendsResponse {
+    pub meta: FundamentalsMeta,
+    pub dividends: Vec<Dividend>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Dividend {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub ex_date: NaiveDateTime,
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct SplitsRequest {
+    #[serde(flatten)]
+    pub instrument: SymbolRequest,
+
+    #[builder(default, setter(strip_option))]
+    pub range: Option<CorporateActionsRange>,
+
+    #[builder(default, setter(strip_option))]
+    pub start_date: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub end_date: Option<NaiveDate>,
+}
+
+impl SplitsRequest {
+    pub fn builder() -> SplitsRequestBuilder {
+        SplitsRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SplitsResponse {
+    pub meta: FundamentalsMeta,
+    pub splits: Vec<Split>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Split {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub date: NaiveDateTime,
+    pub description: String,
+    pub ratio: f64,
+    pub from_factor: f64,
+    pub to_factor: f64,
+}
+
+pub type StatisticsRequest = SymbolRequest;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatisticsResponse {
+    pub meta: FundamentalsMeta,
+    pub statistics: Statistics,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Statistics {
+    pub valuations_metrics: ValuationsMetrics,
+    pub financials: Financials,
+    pub stock_statistics: StockStatistics,
+    pub stock_price_summary: StockPriceSummary,
+    pub dividends_and_splits: DividendsAndSplits,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ValuationsMetrics {
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub market_capitalization: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub enterprise_value: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub trailing_pe: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub forward_pe: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub peg_ratio: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub price_to_sales_ttm: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub price_to_book_mrq: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub enterprise_to_revenue: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub enterprise_to_ebitda: Option<f64>,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Financials {
+    #[serde(default)]
+    pub fiscal_year_ends: Option<NaiveDate>,
+    #[serde(default)]
+    pub most_recent_quarter: Option<NaiveDate>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub profit_margin: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub operating_margin: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub return_on_assets_ttm: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub return_on_equity_ttm: Option<f64>,
+
+    pub income_statement: StatisticsIncomeStatement,
+    pub balance_sheet: StatisticsBalanceSheet,
+    pub cash_flow: StatisticsCashFlow,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatisticsIncomeStatement {
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub revenue_ttm: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub revenue_per_share_ttm: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub quarterly_revenue_growth: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub gross_profit_ttm: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub ebitda: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub net_income_to_common_ttm: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub diluted_eps_ttm: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub quarterly_earnings_growth_yoy: Option<f64>,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatisticsBalanceSheet {
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub total_cash_mrq: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub total_cash_per_share_mrq: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub total_debt_mrq: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub total_debt_to_equity_mrq: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub current_ratio_mrq: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub book_value_per_share_mrq: Option<f64>,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatisticsCashFlow {
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub operating_cash_flow_ttm: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub levered_free_cash_flow_ttm: Option<f64>,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StockStatistics {
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub shares_outstanding: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub float_shares: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub avg_10_volume: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub avg_90_volume: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub shares_short: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub short_ratio: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub short_percent_of_shares_outstanding: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub percent_held_by_insiders: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub percent_held_by_institutions: Option<f64>,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StockPriceSummary {
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub fifty_two_week_low: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub fifty_two_week_high: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub fifty_two_week_change: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub beta: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub day_50_ma: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub day_200_ma: Option<f64>,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DividendsAndSplits {
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub forward_annual_dividend_rate: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub forward_annual_dividend_yield: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub trailing_annual_dividend_rate: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub trailing_annual_dividend_yield: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(rename = "5_year_average_dividend_yield", default)]
+    pub five_year_average_dividend_yield: Option<f64>,
+    #[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+    #[serde(default)]
+    pub payout_ratio: Option<f64>,
+
+    #[serde(default)]
+    pub dividend_frequency: Option<String>,
+    #[serde(default)]
+    pub dividend_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub ex_dividend_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub last_split_factor: Option<String>,
+    #[serde(default)]
+    pub last_split_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Period {
+    #[serde(rename = "annual", alias = "Annual")]
+    Annual,
+
+    #[serde(rename = "quarterly", alias = "Quarterly")]
+    Quarterly,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct FinancialStatementRequest {
+    #[serde(flatten)]
+    pub instrument: SymbolRequest,
+
+    #[builder(default, setter(strip_option))]
+    pub period: Option<Period>,
+
+    #[builder(default, setter(strip_option))]
+    pub start_date: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub end_date: Option<NaiveDate>,
+
+    #[serde(rename = "outputsize")]
+    #[builder(default, setter(strip_option))]
+    pub output_size: Option<u16>,
+}
+
+impl FinancialStatementRequest {
+    pub fn builder() -> FinancialStatementRequestBuilder {
+        FinancialStatementRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FinancialStatementMeta {
+    pub symbol: String,
+    pub name: String,
+    pub currency: String,
+    pub exchange: String,
+    pub mic_code: String,
+    pub exchange_timezone: String,
+    pub period: Period,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FiscalPeriod {
+    pub fiscal_date: NaiveDate,
+
+    #[serde(default)]
+    pub quarter: Option<u8>,
+    #[serde(default)]
+    pub year: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncomeStatementResponse {
+    pub meta: FinancialStatementMeta,
+    pub income_statement: Vec<IncomeStatement>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IncomeStatement {
+    #[serde(flatten)]
+    pub period: FiscalPeriod,
+
+    #[serde(default)]
+    pub sales: Option<f64>,
+    #[serde(default)]
+    pub cost_of_goods: Option<f64>,
+    #[serde(default)]
+    pub gross_profit: Option<f64>,
+    #[serde(default)]
+    pub operating_expense: OperatingExpense,
+    #[serde(default)]
+    pub operating_income: Option<f64>,
+    #[serde(default)]
+    pub non_operating_interest: NonOperatingInterest,
+    #[serde(default)]
+    pub other_income_expense: Option<f64>,
+    #[serde(default)]
+    pub pretax_income: Option<f64>,
+    #[serde(default)]
+    pub income_tax: Option<f64>,
+    #[serde(default)]
+    pub net_income: Option<f64>,
+    #[serde(default)]
+    pub eps_basic: Option<f64>,
+    #[serde(default)]
+    pub eps_diluted: Option<f64>,
+    #[serde(default)]
+    pub basic_shares_outstanding: Option<f64>,
+    #[serde(default)]
+    pub diluted_shares_outstanding: Option<f64>,
+    #[serde(default)]
+    pub ebitda: Option<f64>,
+    #[serde(default)]
+    pub net_income_continuous_operations: Option<f64>,
+    #[serde(default)]
+    pub minority_interests: Option<f64>,
+    #[serde(default)]
+    pub preferred_stock_dividends: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct OperatingExpense {
+    pub research_and_development: Option<f64>,
+    pub selling_general_and_administrative: Option<f64>,
+    pub other_operating_expenses: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct NonOperatingInterest {
+    pub income: Option<f64>,
+    pub expense: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BalanceSheetResponse {
+    pub meta: FinancialStatementMeta,
+    pub balance_sheet: Vec<BalanceSheet>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BalanceSheet {
+    #[serde(flatten)]
+    pub period: FiscalPeriod,
+
+    #[serde(default)]
+    pub assets: Assets,
+    #[serde(default)]
+    pub liabilities: Liabilities,
+    #[serde(default)]
+    pub shareholders_equity: ShareholdersEquity,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Assets {
+    pub current_assets: CurrentAssets,
+    pub non_current_assets: NonCurrentAssets,
+    pub total_assets: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct CurrentAssets {
+    pub cash: Option<f64>,
+    pub cash_equivalents: Option<f64>,
+    pub cash_and_cash_equivalents: Option<f64>,
+    pub other_short_term_investments: Option<f64>,
+    pub accounts_receivable: Option<f64>,
+    pub other_receivables: Option<f64>,
+    pub inventory: Option<f64>,
+    pub prepaid_assets: Option<f64>,
+    pub restricted_cash: Option<f64>,
+    pub assets_held_for_sale: Option<f64>,
+    pub hedging_assets: Option<f64>,
+    pub other_current_assets: Option<f64>,
+    pub total_current_assets: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct NonCurrentAssets {
+    pub properties: Option<f64>,
+    pub land_and_improvements: Option<f64>,
+    pub machinery_furniture_equipment: Option<f64>,
+    pub construction_in_progress: Option<f64>,
+    pub leases: Option<f64>,
+    pub accumulated_depreciation: Option<f64>,
+    pub goodwill: Option<f64>,
+    pub investment_properties: Option<f64>,
+    pub financial_assets: Option<f64>,
+    pub intangible_assets: Option<f64>,
+    pub investments_and_advances: Option<f64>,
+    pub other_non_current_assets: Option<f64>,
+    pub total_non_current_assets: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Liabilities {
+    pub current_liabilities: CurrentLiabilities,
+    pub non_current_liabilities: NonCurrentLiabilities,
+    pub total_liabilities: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct CurrentLiabilities {
+    pub accounts_payable: Option<f64>,
+    pub accrued_expenses: Option<f64>,
+    pub short_term_debt: Option<f64>,
+    pub deferred_revenue: Option<f64>,
+    pub tax_payable: Option<f64>,
+    pub pensions: Option<f64>,
+    pub other_current_liabilities: Option<f64>,
+    pub total_current_liabilities: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct NonCurrentLiabilities {
+    pub long_term_provisions: Option<f64>,
+    pub long_term_debt: Option<f64>,
+    pub provision_for_risks_and_charges: Option<f64>,
+    pub deferred_liabilities: Option<f64>,
+    pub derivative_product_liabilities: Option<f64>,
+    pub other_non_current_liabilities: Option<f64>,
+    pub total_non_current_liabilities: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ShareholdersEquity {
+    pub common_stock: Option<f64>,
+    pub retained_earnings: Option<f64>,
+    pub other_shareholders_equity: Option<f64>,
+    pub total_shareholders_equity: Option<f64>,
+    pub additional_paid_in_capital: Option<f64>,
+    pub treasury_stock: Option<f64>,
+    pub minority_interest: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CashFlowResponse {
+    pub meta: FinancialStatementMeta,
+    pub cash_flow: Vec<CashFlow>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CashFlow {
+    #[serde(flatten)]
+    pub period: FiscalPeriod,
+
+    #[serde(default)]
+    pub operating_activities: OperatingActivities,
+    #[serde(default)]
+    pub investing_activities: InvestingActivities,
+    #[serde(default)]
+    pub financing_activities: FinancingActivities,
+    #[serde(default)]
+    pub end_cash_position: Option<f64>,
+    #[serde(default)]
+    pub income_tax_paid: Option<f64>,
+    #[serde(default)]
+    pub interest_paid: Option<f64>,
+    #[serde(default)]
+    pub free_cash_flow: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct OperatingActivities {
+    pub net_income: Option<f64>,
+    pub depreciation: Option<f64>,
+    pub deferred_taxes: Option<f64>,
+    pub stock_based_compensation: Option<f64>,
+    pub other_non_cash_items: Option<f64>,
+    pub accounts_receivable: Option<f64>,
+    pub accounts_payable: Option<f64>,
+    pub other_assets_liabilities: Option<f64>,
+    pub operating_cash_flow: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct InvestingActivities {
+    pub capital_expenditures: Option<f64>,
+    pub net_intangibles: Option<f64>,
+    pub net_acquisitions: Option<f64>,
+    pub purchase_of_investments: Option<f64>,
+    pub sale_of_investments: Option<f64>,
+    pub other_investing_activity: Option<f64>,
+    pub investing_cash_flow: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FinancingActivities {
+    pub long_term_debt_issuance: Option<f64>,
+    pub long_term_debt_payments: Option<f64>,
+    pub short_term_debt_issuance: Option<f64>,
+    pub common_stock_issuance: Option<f64>,
+    pub common_stock_repurchase: Option<f64>,
+    pub common_dividends: Option<f64>,
+    pub other_financing_charges: Option<f64>,
+    pub financing_cash_flow: Option<f64>,
+}
+
+pub type OwnershipRequest = SymbolRequest;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstitutionalHoldersResponse {
+    pub meta: FundamentalsMeta,
+    pub institutional_holders: Vec<Holder>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FundHoldersResponse {
+    pub meta: FundamentalsMeta,
+    pub fund_holders: Vec<Holder>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Holder {
+    pub entity_name: String,
+
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub date_reported: NaiveDateTime,
+    pub shares: u64,
+    pub value: f64,
+    pub percent_held: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InsiderTransactionsResponse {
+    pub meta: FundamentalsMeta,
+    pub insider_transactions: Vec<InsiderTransaction>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InsiderTransaction {
+    pub full_name: String,
+    pub position: String,
+
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub date_reported: NaiveDateTime,
+    pub is_direct: bool,
+    pub shares: u64,
+
+    #[serde(default)]
+    pub value: Option<f64>,
+    pub description: String,
+}
+
+impl InsiderTransaction {
+    pub fn transaction_type(&self) -> InsiderTransactionType {
+        let description = self.description.to_lowercase();
+        if description.starts_with("sale") {
+            InsiderTransactionType::Sale
+        } else if description.starts_with("purchase") {
+            InsiderTransactionType::Purchase
+        } else if description.starts_with("stock award") {
+            InsiderTransactionType::Award
+        } else if description.starts_with("stock gift") {
+            InsiderTransactionType::Gift
+        } else if description.contains("exercise") {
+            InsiderTransactionType::Exercise
+        } else {
+            InsiderTransactionType::Other
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum InsiderTransactionType {
+    Sale,
+    Purchase,
+    Award,
+    Gift,
+    Exercise,
+    Other,
+}
+
+pub type KeyExecutivesRequest = SymbolRequest;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KeyExecutivesResponse {
+    pub meta: FundamentalsMeta,
+    pub key_executives: Vec<KeyExecutive>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KeyExecutive {
+    pub name: String,
+    pub title: String,
+
+    #[serde(default)]
+    pub age: Option<u16>,
+    #[serde(default)]
+    pub year_born: Option<u16>,
+    #[serde(default)]
+    pub pay: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct MarketCapRequest {
+    #[serde(flatten)]
+    pub instrument: SymbolRequest,
+
+    #[builder(default, setter(strip_option))]
+    pub start_date: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub end_date: Option<NaiveDate>,
+
+    #[serde(rename = "outputsize")]
+    #[builder(default, setter(strip_option))]
+    pub output_size: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub page: Option<u16>,
+}
+
+impl MarketCapRequest {
+    pub fn builder() -> MarketCapRequestBuilder {
+        MarketCapRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MarketCapResponse {
+    #[serde(default)]
+    pub meta: Option<FundamentalsMeta>,
+    pub market_cap: Vec<MarketCap>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MarketCap {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub date: NaiveDateTime,
+    pub value: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum EarningsTime {
+    #[serde(rename = "Pre Market")]
+    PreMarket,
+
+    #[serde(rename = "After Hours")]
+    AfterHours,
+
+    #[serde(rename = "Time Not Supplied", other)]
+    NotSupplied,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct EarningsRequest {
+    #[serde(flatten)]
+    pub instrument: SymbolRequest,
+
+    #[serde(rename = "outputsize")]
+    #[builder(default, setter(strip_option))]
+    pub output_size: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub start_date: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub end_date: Option<NaiveDate>,
+}
+
+impl EarningsRequest {
+    pub fn builder() -> EarningsRequestBuilder {
+        EarningsRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EarningsResponse {
+    pub meta: FundamentalsMeta,
+    pub earnings: Vec<Earnings>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Earnings {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub date: NaiveDateTime,
+    pub time: EarningsTime,
+
+    #[serde(default)]
+    pub eps_estimate: Option<f64>,
+    #[serde(default)]
+    pub eps_actual: Option<f64>,
+    #[serde(default)]
+    pub difference: Option<f64>,
+    #[serde(rename = "surprise_prc", default)]
+    pub surprise_percent: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct DividendsCalendarRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    #[builder(default, setter(strip_option))]
+    pub symbol: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub start_date: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub end_date: Option<NaiveDate>,
+
+    #[serde(rename = "outputsize")]
+    #[builder(default, setter(strip_option))]
+    pub output_size: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub page: Option<u16>,
+}
+
+impl DividendsCalendarRequest {
+    pub fn builder() -> DividendsCalendarRequestBuilder {
+        DividendsCalendarRequestBuilder::default()
+    }
+}
+
+pub type DividendsCalendarResponse = BTreeMap<NaiveDate, Vec<DividendsCalendarEntry>>;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DividendsCalendarEntry {
+    pub symbol: String,
+    pub mic_code: String,
+    pub exchange: String,
+
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub ex_date: NaiveDateTime,
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct SplitsCalendarRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    #[builder(default, setter(strip_option))]
+    pub symbol: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub start_date: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub end_date: Option<NaiveDate>,
+
+    #[serde(rename = "outputsize")]
+    #[builder(default, setter(strip_option))]
+    pub output_size: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub page: Option<u16>,
+}
+
+impl SplitsCalendarRequest {
+    pub fn builder() -> SplitsCalendarRequestBuilder {
+        SplitsCalendarRequestBuilder::default()
+    }
+}
+
+pub type SplitsCalendarResponse = BTreeMap<NaiveDate, Vec<SplitsCalendarEntry>>;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SplitsCalendarEntry {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub date: NaiveDateTime,
+    pub symbol: String,
+    pub mic_code: String,
+    pub exchange: String,
+    pub description: String,
+    pub ratio: f64,
+    pub from_factor: f64,
+    pub to_factor: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct EarningsCalendarRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    #[builder(default, setter(strip_option))]
+    pub start_date: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub end_date: Option<NaiveDate>,
+}
+
+impl EarningsCalendarRequest {
+    pub fn builder() -> EarningsCalendarRequestBuilder {
+        EarningsCalendarRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EarningsCalendarResponse {
+    pub earnings: BTreeMap<NaiveDate, Vec<EarningsCalendarEntry>>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EarningsCalendarEntry {
+    pub symbol: String,
+    pub name: String,
+    pub currency: String,
+    pub exchange: String,
+    pub mic_code: String,
+    pub country: String,
+    pub time: EarningsTime,
+
+    #[serde(default)]
+    pub eps_estimate: Option<f64>,
+    #[serde(default)]
+    pub eps_actual: Option<f64>,
+    #[serde(default)]
+    pub difference: Option<f64>,
+    #[serde(rename = "surprise_prc", default)]
+    pub surprise_percent: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct IpoCalendarRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    #[builder(default, setter(strip_option))]
+    pub start_date: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub end_date: Option<NaiveDate>,
+}
+
+impl IpoCalendarRequest {
+    pub fn builder() -> IpoCalendarRequestBuilder {
+        IpoCalendarRequestBuilder::default()
+    }
+}
+
+pub type IpoCalendarResponse = BTreeMap<NaiveDate, Vec<IpoCalendarEntry>>;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IpoCalendarEntry {
+    pub symbol: String,
+    pub name: String,
+    pub exchange: String,
+    pub mic_code: String,
+
+    #[serde(default)]
+    pub price_range_low: Option<f64>,
+    #[serde(default)]
+    pub price_range_high: Option<f64>,
+    #[serde(default)]
+    pub offer_price: Option<f64>,
+    #[serde(default)]
+    pub currency: Option<String>,
+    #[serde(default)]
+    pub shares: Option<f64>,
+}
+
+pub(crate) fn group_by_date<T>(
+    entries: Vec<T>,
+    date: impl Fn(&T) -> NaiveDate,
+) -> BTreeMap<NaiveDate, Vec<T>> {
+    let mut grouped = BTreeMap::<NaiveDate, Vec<T>>::new();
+    for entry in entries {
+        grouped.entry(date(&entry)).or_default().push(entry);
+    }
+
+    grouped
+}
+
+#[cfg(test)]
+mod test {
+    use tokio_test::assert_ok;
+
+    use super::*;
+
+    #[test]
+    pub fn test_symbol_request() {
+        let request = SymbolRequest::builder()
+            .symbol("AAPL".into())
+            .mic_code("XNGS".into())
+            .build()
+            .unwrap();
+
+        let query = serde_urlencoded::to_string(&request);
+
+        assert_ok!(&query);
+
+        assert_eq!("symbol=AAPL&mic_code=XNGS", query.unwrap());
+    }
+
+    #[test]
+    pub fn test_profile_response() {
+        let response = r#"{"symbol":"AAPL","name":"Apple Inc","exchange":"NASDAQ","mic_code":"XNAS","sector":"Technology","industry":"Consumer Electronics","employees":147000,"website":"http://www.apple.com","description":"Apple Inc. designs, manufactures, and markets smartphones, personal computers, tablets, wearables, and accessories worldwide.","type":"Common Stock","CEO":"Mr. Timothy D. Cook","address":"One Apple Park Way","address2":"","city":"Cupertino","zip":"95014","state":"CA","country":"US","phone":"408-996-1010"}"#;
+
+        let response = serde_json::from_str::<ProfileResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(Some(147000), res.employees);
+        assert_eq!(Some("Mr. Timothy D. Cook".into()), res.ceo);
+    }
+
+    #[test]
+    pub fn test_dividends_request() {
+        let request = DividendsRequest::builder()
+            .instrument(
+                SymbolRequest::builder()
+                    .symbol("AAPL".into())
+                    .country("United States".into())
+                    .build()
+                    .unwrap(),
+            )
+            .range(CorporateActionsRange::Full)
+            .build()
+            .unwrap();
+
+        let query = serde_urlencoded::to_string(&request);
+
+        assert_ok!(&query);
+
+        assert_eq!(
+            "symbol=AAPL&country=United+States&range=full",
+            query.unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_dividends_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York"},"dividends":[{"ex_date":"2022-08-05","amount":0.23},{"ex_date":"2022-05-06","amount":0.23}]}"#;
+
+        let response = serde_json::from_str::<DividendsResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(2, res.dividends.len());
+        assert_eq!(0.23, res.dividends[0].amount);
+    }
+
+    #[test]
+    pub fn test_splits_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York"},"splits":[{"date":"2020-08-31","description":"4-for-1 split","ratio":0.25,"from_factor":4,"to_factor":1}]}"#;
+
+        let response = serde_json::from_str::<SplitsResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2020, 8, 31)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+            res.splits[0].date
+        );
+        assert_eq!(4.0, res.splits[0].from_factor);
+    }
+
+    #[test]
+    pub fn test_dividends_calendar_grouping() {
+        let response = r#"[{"symbol":"MSFT","mic_code":"XNGS","exchange":"NASDAQ","ex_date":"2024-02-14","amount":0.75},{"symbol":"AAPL","mic_code":"XNGS","exchange":"NASDAQ","ex_date":"2024-02-09","amount":0.24},{"symbol":"CSCO","mic_code":"XNGS","exchange":"NASDAQ","ex_date":"2024-02-14","amount":0.4}]"#;
+
+        let response = serde_json::from_str::<Vec<DividendsCalendarEntry>>(response);
+
+        assert_ok!(&response);
+
+        let res = group_by_date(response.unwrap(), |e| e.ex_date.date());
+        assert_eq!(2, res.len());
+        assert_eq!(2, res[&NaiveDate::from_ymd_opt(2024, 2, 14).unwrap()].len());
+    }
+
+    #[test]
+    pub fn test_earnings_calendar_response() {
+        let response = r#"{"earnings":{"2024-04-08":[{"symbol":"BSVN","name":"Bank7 Corp","currency":"USD","exchange":"NASDAQ","mic_code":"XNGS","country":"United States","time":"After Hours","eps_estimate":0.88,"eps_actual":0.91,"difference":0.03,"surprise_prc":3.41},{"symbol":"GNTY","name":"Guaranty Bancshares Inc","currency":"USD","exchange":"NYSE","mic_code":"XNYS","country":"United States","time":"Time Not Supplied","eps_estimate":null,"eps_actual":null,"difference":null,"surprise_prc":null}]},"status":"ok"}"#;
+
+        let response = serde_json::from_str::<EarningsCalendarResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        let day = &res.earnings[&NaiveDate::from_ymd_opt(2024, 4, 8).unwrap()];
+        assert!(matches!(day[0].time, EarningsTime::AfterHours));
+        assert_eq!(Some(3.41), day[0].surprise_percent);
+        assert!(day[1].eps_actual.is_none());
+    }
+
+    #[test]
+    pub fn test_ipo_calendar_response() {
+        let response = r#"{"2021-09-14":[{"symbol":"DICE","name":"DICE Therapeutics Inc","exchange":"NASDAQ","mic_code":"XNGS","price_range_low":16,"price_range_high":18,"offer_price":17,"currency":"USD","shares":8823529}]}"#;
+
+        let response = serde_json::from_str::<IpoCalendarResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(
+            Some(17.0),
+            res[&NaiveDate::from_ymd_opt(2021, 9, 14).unwrap()][0].offer_price
+        );
+    }
+
+    #[test]
+    pub fn test_earnings_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York"},"earnings":[{"date":"2022-10-27","time":"After Hours","eps_estimate":1.27,"eps_actual":1.29,"difference":0.02,"surprise_prc":1.57},{"date":"2022-07-28","time":"Pre Market","eps_estimate":1.16,"eps_actual":1.2,"difference":0.04,"surprise_prc":3.45}],"status":"ok"}"#;
+
+        let response = serde_json::from_str::<EarningsResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert!(matches!(res.earnings[1].time, EarningsTime::PreMarket));
+        assert_eq!(Some(1.29), res.earnings[0].eps_actual);
+    }
+
+    #[test]
+    pub fn test_statistics_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York"},"statistics":{"valuations_metrics":{"market_capitalization":2546807865344,"enterprise_value":2620597731328,"trailing_pe":30.162493,"forward_pe":26.982489,"peg_ratio":1.4153,"price_to_sales_ttm":7.183,"price_to_book_mrq":39.3061,"enterprise_to_revenue":7.39,"enterprise_to_ebitda":22.838},"financials":{"fiscal_year_ends":"2020-09-26","most_recent_quarter":"2021-06-26","profit_margin":0.25004,"operating_margin":0.28788,"return_on_assets_ttm":0.19302,"return_on_equity_ttm":1.27125,"income_statement":{"revenue_ttm":347155005440,"revenue_per_share_ttm":20.61,"quarterly_revenue_growth":0.364,"gross_profit_ttm":104956000000,"ebitda":114745004032,"net_income_to_common_ttm":86801997824,"diluted_eps_ttm":5.108,"quarterly_earnings_growth_yoy":0.932},"balance_sheet":{"total_cash_mrq":61696000000,"total_cash_per_share_mrq":3.732,"total_debt_mrq":135491002368,"total_debt_to_equity_mrq":210.782,"current_ratio_mrq":1.062,"book_value_per_share_mrq":3.882},"cash_flow":{"operating_cash_flow_ttm":104414003200,"levered_free_cash_flow_ttm":80625876992}},"stock_statistics":{"shares_outstanding":16530199552,"float_shares":16513305231,"avg_10_volume":72804757,"avg_90_volume":77013078,"shares_short":93105968,"short_ratio":1.19,"short_percent_of_shares_outstanding":0.0056,"percent_held_by_insiders":0.0007,"percent_held_by_institutions":0.58241},"stock_price_summary":{"fifty_two_week_low":103.1,"fifty_two_week_high":157.26,"fifty_two_week_change":0.34743,"beta":1.201965,"day_50_ma":148.96686,"day_200_ma":134.42506},"dividends_and_splits":{"forward_annual_dividend_rate":0.88,"forward_annual_dividend_yield":0.0057,"trailing_annual_dividend_rate":0.835,"trailing_annual_dividend_yield":0.005739,"5_year_average_dividend_yield":1.18,"payout_ratio":0.1629,"dividend_frequency":"Quarterly","dividend_date":"2021-08-12","ex_dividend_date":"2021-08-06","last_split_factor":"4-for-1 split","last_split_date":"2020-08-31"}}}"#;
+
+        let response = serde_json::from_str::<StatisticsResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap().statistics;
+        assert_eq!(Some(30.162493), res.valuations_metrics.trailing_pe);
+        assert_eq!(
+            Some(1.18),
+            res.dividends_and_splits.five_year_average_dividend_yield
+        );
+        assert_eq!(
+            Some(NaiveDate::from_ymd_opt(2020, 9, 26).unwrap()),
+            res.financials.fiscal_year_ends
+        );
+    }
+
+    #[test]
+    pub fn test_statistics_with_nulls() {
+        let response = r#"{"valuations_metrics":{"market_capitalization":"2546807865344","trailing_pe":null},"financials":{"income_statement":{},"balance_sheet":{},"cash_flow":{}},"stock_statistics":{},"stock_price_summary":{},"dividends_and_splits":{"payout_ratio":null}}"#;
+
+        let response = serde_json::from_str::<Statistics>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(
+            Some(2546807865344.0),
+            res.valuations_metrics.market_capitalization
+        );
+        assert!(res.valuations_metrics.trailing_pe.is_none());
+    }
+
+    #[test]
+    pub fn test_income_statement_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York","period":"Quarterly"},"income_statement":[{"fiscal_date":"2021-12-31","quarter":1,"year":2022,"sales":123945000000,"cost_of_goods":69702000000,"gross_profit":54243000000,"operating_expense":{"research_and_development":6306000000,"selling_general_and_administrative":6449000000,"other_operating_expenses":null},"operating_income":41488000000,"non_operating_interest":{"income":650000000,"expense":694000000},"other_income_expense":-203000000,"pretax_income":41241000000,"income_tax":6611000000,"net_income":34630000000,"eps_basic":2.11,"eps_diluted":2.1,"basic_shares_outstanding":16391724000,"diluted_shares_outstanding":16391724000,"ebitda":44632000000,"net_income_continuous_operations":null,"minority_interests":null,"preferred_stock_dividends":null}]}"#;
+
+        let response = serde_json::from_str::<IncomeStatementResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert!(matches!(res.meta.period, Period::Quarterly));
+
+        let statement = &res.income_statement[0];
+        assert_eq!(Some(1), statement.period.quarter);
+        assert_eq!(
+            Some(6306000000.0),
+            statement.operating_expense.research_and_development
+        );
+        assert!(statement.minority_interests.is_none());
+    }
+
+    #[test]
+    pub fn test_cash_flow_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York","period":"Annual"},"cash_flow":[{"fiscal_date":"2021-09-30","operating_activities":{"net_income":94680000000,"operating_cash_flow":104038000000},"investing_activities":{"capital_expenditures":-11085000000,"net_intangibles":null},"financing_activities":{"common_dividends":-14467000000},"end_cash_position":35929000000,"free_cash_flow":92953000000}]}"#;
+
+        let response = serde_json::from_str::<CashFlowResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        let cash_flow = &res.cash_flow[0];
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2021, 9, 30).unwrap(),
+            cash_flow.period.fiscal_date
+        );
+        assert!(cash_flow.period.quarter.is_none());
+        assert_eq!(Some(92953000000.0), cash_flow.free_cash_flow);
+    }
+
+    #[test]
+    pub fn test_institutional_holders_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York"},"institutional_holders":[{"entity_name":"Vanguard Group, Inc. (The)","date_reported":"2022-09-29","shares":1299997926,"value":197415686592,"percent_held":0.0817},{"entity_name":"Blackrock Inc.","date_reported":"2022-09-29","shares":1036170624,"value":157352873856,"percent_held":0.0651}]}"#;
+
+        let response = serde_json::from_str::<InstitutionalHoldersResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(2, res.institutional_holders.len());
+        assert_eq!(0.0817, res.institutional_holders[0].percent_held);
+    }
+
+    #[test]
+    pub fn test_insider_transactions_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York"},"insider_transactions":[{"full_name":"ADAMS KATHERINE L","position":"General Counsel","date_reported":"2021-10-01","is_direct":true,"shares":43000,"value":6092100,"description":"Sale at price 141.11 - 142.11 per share."},{"full_name":"KONDO CHRISTOPHER","position":"Officer","date_reported":"2021-09-30","is_direct":true,"shares":9233,"value":null,"description":"Stock Award(Grant) at price 0.00 per share."}]}"#;
+
+        let response = serde_json::from_str::<InsiderTransactionsResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(
+            InsiderTransactionType::Sale,
+            res.insider_transactions[0].transaction_type()
+        );
+        assert_eq!(
+            InsiderTransactionType::Award,
+            res.insider_transactions[1].transaction_type()
+        );
+    }
+
+    #[test]
+    pub fn test_key_executives_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York"},"key_executives":[{"name":"Mr. Timothy D. Cook","title":"CEO & Director","age":60,"year_born":1961,"pay":14769259},{"name":"Ms. Nancy Paxton","title":"Sr. Director of Investor Relations & Treasury","age":null,"year_born":null,"pay":null}]}"#;
+
+        let response = serde_json::from_str::<KeyExecutivesResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(Some(1961), res.key_executives[0].year_born);
+        assert!(res.key_executives[1].pay.is_none());
+    }
+
+    #[test]
+    pub fn test_market_cap_response() {
+        let response = r#"{"market_cap":[{"date":"2023-10-26","value":2588254572000},{"date":"2023-10-25","value":2662354852000}]}"#;
+
+        let response = serde_json::from_str::<MarketCapResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(2, res.market_cap.len());
+        assert_eq!(2588254572000.0, res.market_cap[0].value);
+    }
 }
@@ -0,0 +1,421 @@
+use chrono::NaiveDate;
+use derive_builder::Builder;
+use serde_derive::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct FundRequest {
+    pub symbol: String,
+
+    #[builder(default, setter(strip_option))]
+    pub country: Option<String>,
+
+    #[serde(rename = "dp")]
+    #[builder(default, setter(strip_option))]
+    pub decimal_places: Option<u8>,
+}
+
+impl FundRequest {
+    pub fn builder() -> FundRequestBuilder {
+        FundRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FundResponse<T> {
+    #[serde(alias = "etf", alias = "mutual_fund")]
+    pub fund: T,
+    pub status: String,
+}
+
+pub type EtfSummaryResponse = FundResponse<SummarySection>;
+pub type EtfPerformanceResponse = FundResponse<PerformanceSection>;
+pub type EtfRiskResponse = FundResponse<RiskSection>;
+pub type EtfCompositionResponse = FundResponse<CompositionSection>;
+
+pub type MutualFundSummaryResponse = FundResponse<SummarySection>;
+pub type MutualFundPerformanceResponse = FundResponse<PerformanceSection>;
+pub type MutualFundRiskResponse = FundResponse<RiskSection>;
+pub type MutualFundCompositionResponse = FundResponse<CompositionSection>;
+pub type MutualFundPurchaseInfoResponse = FundResponse<PurchaseInfoSection>;
+pub type MutualFundSustainabilityResponse = FundResponse<SustainabilitySection>;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SummarySection {
+    pub summary: FundSummary,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PerformanceSection {
+    pub performance: FundPerformance,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RiskSection {
+    pub risk: FundRisk,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CompositionSection {
+    pub composition: FundComposition,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurchaseInfoSection {
+    pub purchase_info: FundPurchaseInfo,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SustainabilitySection {
+    pub sustainability: FundSustainability,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FundSummary {
+    pub symbol: String,
+    pub name: String,
+
+    #[serde(default)]
+    pub fund_family: Option<String>,
+    #[serde(default)]
+    pub fund_type: Option<String>,
+    #[serde(default)]
+    pub currency: Option<String>,
+    #[serde(default)]
+    pub share_class_inception_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub ytd_return: Option<f64>,
+    #[serde(default)]
+    pub expense_ratio_net: Option<f64>,
+    #[serde(rename = "yield", default)]
+    pub fund_yield: Option<f64>,
+    #[serde(default)]
+    pub nav: Option<f64>,
+    #[serde(default)]
+    pub last_price: Option<f64>,
+    #[serde(default)]
+    pub min_investment: Option<f64>,
+    #[serde(default)]
+    pub turnover_rate: Option<f64>,
+    #[serde(default)]
+    pub net_assets: Option<f64>,
+    #[serde(default)]
+    pub overview: Option<String>,
+    #[serde(default)]
+    pub people: Vec<FundManager>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FundManager {
+    pub name: String,
+
+    #[serde(default)]
+    pub tenure_since: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FundPerformance {
+    pub trailing_returns: Vec<TrailingReturn>,
+    pub annual_total_returns: Vec<AnnualTotalReturn>,
+    pub quarterly_total_returns: Vec<QuarterlyTotalReturn>,
+    pub load_adjusted_return: Vec<LoadAdjustedReturn>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TrailingReturn {
+    pub period: String,
+
+    #[serde(default)]
+    pub share_class_return: Option<f64>,
+    #[serde(default)]
+    pub category_return: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnnualTotalReturn {
+    pub year: i32,
+
+    #[serde(default)]
+    pub share_class_return: Option<f64>,
+    #[serde(default)]
+    pub category_return: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QuarterlyTotalReturn {
+    pub year: i32,
+
+    #[serde(default)]
+    pub q1: Option<f64>,
+    #[serde(default)]
+    pub q2: Option<f64>,
+    #[serde(default)]
+    pub q3: Option<f64>,
+    #[serde(default)]
+    pub q4: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoadAdjustedReturn {
+    pub period: String,
+
+    #[serde(rename = "return", default)]
+    pub return_value: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FundRisk {
+    pub volatility_measures: Vec<VolatilityMeasure>,
+    pub valuation_metrics: FundValuationMetrics,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VolatilityMeasure {
+    pub period: String,
+
+    #[serde(default)]
+    pub alpha: Option<f64>,
+    #[serde(default)]
+    pub alpha_category: Option<f64>,
+    #[serde(default)]
+    pub beta: Option<f64>,
+    #[serde(default)]
+    pub beta_category: Option<f64>,
+    #[serde(default)]
+    pub mean_annual_return: Option<f64>,
+    #[serde(default)]
+    pub mean_annual_return_category: Option<f64>,
+    #[serde(default)]
+    pub r_squared: Option<f64>,
+    #[serde(default)]
+    pub r_squared_category: Option<f64>,
+    #[serde(default)]
+    pub std: Option<f64>,
+    #[serde(default)]
+    pub std_category: Option<f64>,
+    #[serde(default)]
+    pub sharpe_ratio: Option<f64>,
+    #[serde(default)]
+    pub sharpe_ratio_category: Option<f64>,
+    #[serde(default)]
+    pub treynor_ratio: Option<f64>,
+    #[serde(default)]
+    pub treynor_ratio_category: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FundValuationMetrics {
+    pub price_to_earnings: Option<f64>,
+    pub price_to_book: Option<f64>,
+    pub price_to_sales: Option<f64>,
+    pub price_to_cashflow: Option<f64>,
+    pub sales_growth: Option<f64>,
+    pub earnings_growth: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FundComposition {
+    pub major_market_sectors: Vec<SectorWeight>,
+    pub country_allocation: Vec<CountryAllocation>,
+    pub asset_allocation: AssetAllocation,
+    pub top_holdings: Vec<FundHolding>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SectorWeight {
+    pub sector: String,
+    pub weight: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CountryAllocation {
+    pub country: String,
+    pub allocation: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct AssetAllocation {
+    pub cash: Option<f64>,
+    pub stocks: Option<f64>,
+    pub preferred_stocks: Option<f64>,
+    pub convertables: Option<f64>,
+    pub bonds: Option<f64>,
+    pub others: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FundHolding {
+    pub symbol: String,
+    pub name: String,
+
+    #[serde(default)]
+    pub exchange: Option<String>,
+    #[serde(default)]
+    pub mic_code: Option<String>,
+    pub weight: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FundPurchaseInfo {
+    pub expenses: FundExpenses,
+    pub minimums: FundMinimums,
+    pub pricing: FundPricing,
+    pub brokerages: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FundExpenses {
+    pub expense_ratio_gross: Option<f64>,
+    pub expense_ratio_net: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FundMinimums {
+    pub initial_investment: Option<f64>,
+    pub additional_investment: Option<f64>,
+    pub initial_ira_investment: Option<f64>,
+    pub additional_ira_investment: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FundPricing {
+    pub nav: Option<f64>,
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct FundSustainability {
+    pub score: Option<f64>,
+    pub corporate_esg_pillars: EsgPillars,
+    pub sustainable_investment: Option<bool>,
+    pub corporate_aum: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct EsgPillars {
+    pub environmental: Option<f64>,
+    pub social: Option<f64>,
+    pub governance: Option<f64>,
+}
+
+#[cfg(test)]
+mod test {
+    use tokio_test::assert_ok;
+
+    use super::*;
+
+    #[test]
+    pub fn test_etf_summary_response() {
+        let response = r#"{"etf":{"summary":{"symbol":"IVV","name":"iShares Core S&P 500 ETF","fund_family":"iShares","fund_type":"Large Blend","currency":"USD","share_class_inception_date":"2000-05-15","ytd_return":-0.0537,"expense_ratio_net":0.0003,"yield":0.0133,"nav":430.7,"last_price":430.5,"turnover_rate":0.04,"net_assets":307872038912,"overview":"The investment seeks to track the S&P 500."}},"status":"ok"}"#;
+
+        let response = serde_json::from_str::<EtfSummaryResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap().fund.summary;
+        assert_eq!("IVV", res.symbol);
+        assert_eq!(Some(0.0133), res.fund_yield);
+        assert!(res.min_investment.is_none());
+    }
+
+    #[test]
+    pub fn test_mutual_fund_composition_response() {
+        let response = r#"{"mutual_fund":{"composition":{"major_market_sectors":[{"sector":"Technology","weight":0.2424},{"sector":"Healthcare","weight":0.1442}],"asset_allocation":{"cash":0.0004,"stocks":0.9996,"preferred_stocks":0,"convertables":0,"bonds":0,"others":0},"top_holdings":[{"symbol":"AAPL","name":"Apple Inc","exchange":"NASDAQ","mic_code":"XNAS","weight":0.0592}]}},"status":"ok"}"#;
+
+        let response = serde_json::from_str::<MutualFundCompositionResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap().fund.composition;
+        assert_eq!(2, res.major_market_sectors.len());
+        assert!(res.country_allocation.is_empty());
+        assert_eq!(Some(0.9996), res.asset_allocation.stocks);
+        assert_eq!("AAPL", res.top_holdings[0].symbol);
+    }
+
+    #[test]
+    pub fn test_etf_summary_missing_symbol() {
+        let response = r#"{"etf":{"summary":{"name":"iShares Core S&P 500 ETF"}},"status":"ok"}"#;
+
+        let response = serde_json::from_str::<EtfSummaryResponse>(response);
+
+        assert!(response.is_err());
+    }
+
+    #[test]
+    pub fn test_etf_performance_response() {
+        let response = r#"{"etf":{"performance":{"trailing_returns":[{"period":"ytd","share_class_return":-0.0537,"category_return":-0.0412},{"period":"1y","share_class_return":0.1623,"category_return":null}],"annual_total_returns":[{"year":2022,"share_class_return":-0.1811,"category_return":-0.1652}],"quarterly_total_returns":[{"year":2022,"q1":-0.046,"q2":-0.1613,"q3":-0.0489,"q4":0.0755}],"load_adjusted_return":[{"period":"1y","return":0.1623},{"period":"5y","return":null}]}},"status":"ok"}"#;
+
+        let response = serde_json::from_str::<EtfPerformanceResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap().fund.performance;
+        assert_eq!("ytd", res.trailing_returns[0].period);
+        assert!(res.trailing_returns[1].category_return.is_none());
+        assert_eq!(2022, res.annual_total_returns[0].year);
+        assert_eq!(Some(0.0755), res.quarterly_total_returns[0].q4);
+        assert_eq!(Some(0.1623), res.load_adjusted_return[0].return_value);
+        assert!(res.load_adjusted_return[1].return_value.is_none());
+    }
+
+    #[test]
+    pub fn test_mutual_fund_risk_response() {
+        let response = r#"{"mutual_fund":{"risk":{"volatility_measures":[{"period":"3y","alpha":-0.02,"alpha_category":0.01,"beta":1,"beta_category":0.98,"mean_annual_return":1.01,"r_squared":100,"std":17.88,"sharpe_ratio":0.66,"treynor_ratio":11.55}],"valuation_metrics":{"price_to_earnings":20.19,"price_to_book":3.72,"price_to_sales":2.36,"price_to_cashflow":14.13,"sales_growth":7.72,"earnings_growth":null}}},"status":"ok"}"#;
+
+        let response = serde_json::from_str::<MutualFundRiskResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap().fund.risk;
+        assert_eq!("3y", res.volatility_measures[0].period);
+        assert_eq!(Some(17.88), res.volatility_measures[0].std);
+        assert!(res.volatility_measures[0].std_category.is_none());
+        assert_eq!(Some(20.19), res.valuation_metrics.price_to_earnings);
+        assert!(res.valuation_metrics.earnings_growth.is_none());
+    }
+
+    #[test]
+    pub fn test_mutual_fund_purchase_info_response() {
+        let response = r#"{"mutual_fund":{"purchase_info":{"expenses":{"expense_ratio_gross":0.0014,"expense_ratio_net":0.0014},"minimums":{"initial_investment":3000,"additional_investment":1,"initial_ira_investment":null,"additional_ira_investment":null},"pricing":{"nav":398.81,"currency":"USD"},"brokerages":["Fidelity","Vanguard"]}},"status":"ok"}"#;
+
+        let response = serde_json::from_str::<MutualFundPurchaseInfoResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap().fund.purchase_info;
+        assert_eq!(Some(0.0014), res.expenses.expense_ratio_net);
+        assert_eq!(Some(3000.0), res.minimums.initial_investment);
+        assert!(res.minimums.initial_ira_investment.is_none());
+        assert_eq!(Some("USD".into()), res.pricing.currency);
+        assert_eq!(2, res.brokerages.len());
+    }
+
+    #[test]
+    pub fn test_mutual_fund_sustainability_response() {
+        let response = r#"{"mutual_fund":{"sustainability":{"score":21.54,"corporate_esg_pillars":{"environmental":4.64,"social":9.54,"governance":7.36},"sustainable_investment":false,"corporate_aum":0.9889}},"status":"ok"}"#;
+
+        let response = serde_json::from_str::<MutualFundSustainabilityResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap().fund.sustainability;
+        assert_eq!(Some(21.54), res.score);
+        assert_eq!(Some(9.54), res.corporate_esg_pillars.social);
+        assert_eq!(Some(false), res.sustainable_investment);
+        assert_eq!(Some(0.9889), res.corporate_aum);
+    }
+}
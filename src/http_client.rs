@@ -9,6 +9,8 @@ pub struct Response {
 #[async_trait]
 pub trait HttpClient {
     async fn get(&self, url: &str, api_key: &str) -> Result<Response>;
+
+    async fn post(&self, url: &str, api_key: &str, body: &str) -> Result<Response>;
 }
 
 #[cfg(feature = "reqwest-client")]
@@ -17,6 +19,18 @@ impl HttpClient for reqwest::Client {
     async fn get(&self, url: &str, api_key: &str) -> Result<Response> {
         Response::from(self.get(url).query(&[("apikey", api_key)]).send().await?).await
     }
+
+    async fn post(&self, url: &str, api_key: &str, body: &str) -> Result<Response> {
+        Response::from(
+            self.post(url)
+                .query(&[("apikey", api_key)])
+                .header("Content-Type", "application/json")
+                .body(body.to_owned())
+                .send()
+                .await?,
+        )
+        .await
+    }
 }
 
 #[cfg(feature = "reqwest-client")]
@@ -41,6 +55,18 @@ impl HttpClient for surf::Client {
         )
         .await
     }
+
+    async fn post(&self, url: &str, api_key: &str, body: &str) -> Result<Response> {
+        Response::from(
+            self.post(url)
+                .header("Authorization", format!("apikey {}", api_key))
+                .body_string(body.to_owned())
+                .header("Content-Type", "application/json")
+                .send()
+                .await?,
+        )
+        .await
+    }
 }
 
 #[cfg(feature = "surf-client")]
@@ -66,6 +92,18 @@ impl HttpClient for wreq::Client {
         )
         .await
     }
+
+    async fn post(&self, url: &str, api_key: &str, body: &str) -> Result<Response> {
+        Response::from(
+            self.post(url)
+                .header("Authorization", format!("apikey {}", api_key))
+                .header("Content-Type", "application/json")
+                .body(body.to_owned())
+                .send()
+                .await?,
+        )
+        .await
+    }
 }
 
 #[cfg(feature = "wreq-client")]
@@ -1,28 +1,130 @@
+use crate::analysis::AnalysisRequest;
+use crate::analysis::AnalystRatingsRequest;
+use crate::analysis::AnalystRatingsResponse;
+use crate::analysis::EarningsEstimateResponse;
+use crate::analysis::EpsRevisionsResponse;
+use crate::analysis::EpsTrendResponse;
+use crate::analysis::GrowthEstimatesResponse;
+use crate::analysis::PriceTargetResponse;
+use crate::analysis::RecommendationsResponse;
+use crate::analysis::RevenueEstimateResponse;
+use crate::core::ComplexDataRequest;
+use crate::core::ComplexDataSeries;
+use crate::core::CurrencyConversionRequest;
+use crate::core::CurrencyConversionResponse;
+use crate::core::EarliestTimestampRequest;
+use crate::core::EarliestTimestampResponse;
+use crate::core::EodRequest;
+use crate::core::EodResponse;
+use crate::core::ExchangeRateRequest;
+use crate::core::ExchangeRateResponse;
+use crate::core::MarketMoversRequest;
+use crate::core::MarketMoversResponse;
 use crate::core::PriceRequest;
 use crate::core::PriceResponse;
+use crate::core::PricesRequest;
 use crate::core::QuoteRequest;
 use crate::core::QuoteResponse;
+use crate::core::QuotesRequest;
+use crate::core::TimeSeriesBatchRequest;
 use crate::core::TimeSeriesRequest;
 use crate::core::TimeSeriesResponse;
+use fundamentals::group_by_date;
+use fundamentals::BalanceSheetResponse;
+use fundamentals::CashFlowResponse;
+use fundamentals::DividendsCalendarRequest;
+use fundamentals::DividendsCalendarResponse;
+use fundamentals::DividendsRequest;
+use fundamentals::DividendsResponse;
+use fundamentals::EarningsCalendarRequest;
+use fundamentals::EarningsCalendarResponse;
+use fundamentals::EarningsRequest;
+use fundamentals::EarningsResponse;
+use fundamentals::FinancialStatementRequest;
+use fundamentals::FundHoldersResponse;
+use fundamentals::IncomeStatementResponse;
+use fundamentals::InsiderTransactionsResponse;
+use fundamentals::InstitutionalHoldersResponse;
+use fundamentals::IpoCalendarRequest;
+use fundamentals::IpoCalendarResponse;
+use fundamentals::KeyExecutivesRequest;
+use fundamentals::KeyExecutivesResponse;
 use fundamentals::LogoRequest;
 use fundamentals::LogoResponse;
+use fundamentals::MarketCapRequest;
+use fundamentals::MarketCapResponse;
+use fundamentals::OwnershipRequest;
+use fundamentals::ProfileRequest;
+use fundamentals::ProfileResponse;
+use fundamentals::SplitsCalendarRequest;
+use fundamentals::SplitsCalendarResponse;
+use fundamentals::SplitsRequest;
+use fundamentals::SplitsResponse;
+use fundamentals::StatisticsRequest;
+use fundamentals::StatisticsResponse;
+use funds::EtfCompositionResponse;
+use funds::EtfPerformanceResponse;
+use funds::EtfRiskResponse;
+use funds::EtfSummaryResponse;
+use funds::FundRequest;
+use funds::MutualFundCompositionResponse;
+use funds::MutualFundPerformanceResponse;
+use funds::MutualFundPurchaseInfoResponse;
+use funds::MutualFundRiskResponse;
+use funds::MutualFundSummaryResponse;
+use funds::MutualFundSustainabilityResponse;
+use options::OptionsChainRequest;
+use options::OptionsChainResponse;
+use options::OptionsExpirationRequest;
+use options::OptionsExpirationResponse;
+use reference::CryptocurrenciesRequest;
+use reference::CryptocurrenciesResponse;
+use reference::ExchangesRequest;
+use reference::ExchangesResponse;
+use reference::ForexPairsRequest;
+use reference::ForexPairsResponse;
+use reference::MarketStateRequest;
+use reference::MarketStateResponse;
+use reference::StocksRequest;
+use reference::StocksResponse;
+use reference::SymbolSearchRequest;
+use reference::SymbolSearchResponse;
+use regulatory::DirectHoldersResponse;
+use regulatory::EdgarFilingsRequest;
+use regulatory::EdgarFilingsResponse;
+use regulatory::RegulatoryRequest;
+use regulatory::TaxInfoResponse;
 use serde_derive::Deserialize;
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 use std::fmt::Display;
+use technical::IndicatorRequest;
+use technical::IndicatorResponse;
+use technical::TechnicalIndicator;
 
 use errors::{Error, Result};
-use http_client::HttpClient;
+use http_client::{HttpClient, Response};
 use serde_derive::Serialize;
 
 use derive_builder::Builder;
 
+pub mod analysis;
 pub mod core;
 pub mod errors;
 pub mod fundamentals;
+pub mod funds;
 pub mod http_client;
+pub mod options;
+pub mod reference;
+pub mod regulatory;
+pub mod technical;
 
 const API_URL: &str = "https://api.twelvedata.com";
 
+// error codes that concern the request as a whole (authentication, plan
+// limits, exhausted credits, server failure) rather than a single symbol
+const REQUEST_ERROR_CODES: [u64; 4] = [401, 403, 429, 500];
+
 pub struct TwelveData {
     api_key: String,
     client: Box<dyn HttpClient + Send + Sync>,
@@ -40,52 +142,448 @@ impl TwelveData {
         self.send("time_series", &req).await
     }
 
+    pub async fn time_series_batch(
+        &self,
+        req: TimeSeriesBatchRequest,
+    ) -> Result<HashMap<String, Result<TimeSeriesResponse>>> {
+        self.send_batch("time_series", &req, &req.symbols).await
+    }
+
     pub async fn quote(&self, req: QuoteRequest) -> Result<QuoteResponse> {
         self.send("quote", &req).await
     }
 
+    pub async fn quotes(
+        &self,
+        req: QuotesRequest,
+    ) -> Result<HashMap<String, Result<QuoteResponse>>> {
+        self.send_batch("quote", &req, &req.symbols).await
+    }
+
     pub async fn price(&self, req: PriceRequest) -> Result<PriceResponse> {
         self.send("price", &req).await
     }
 
+    pub async fn prices(
+        &self,
+        req: PricesRequest,
+    ) -> Result<HashMap<String, Result<PriceResponse>>> {
+        self.send_batch("price", &req, &req.symbols).await
+    }
+
+    pub async fn complex_data(
+        &self,
+        req: ComplexDataRequest,
+    ) -> Result<HashMap<String, Vec<Result<ComplexDataSeries>>>> {
+        let url = format!("{}/complex_data", API_URL);
+        let body = serde_json::to_string(&req)?;
+
+        let res = self.client.post(&url, &self.api_key, &body).await?;
+        let val = parse_response(res)?;
+
+        parse_complex_data(val, &req.symbols, req.intervals.len() * req.methods.len())
+    }
+
+    pub async fn market_movers(&self, req: MarketMoversRequest) -> Result<MarketMoversResponse> {
+        self.send(&format!("market_movers/{}", req.market), &req)
+            .await
+    }
+
+    pub async fn indicator<I: TechnicalIndicator>(
+        &self,
+        req: IndicatorRequest<I>,
+    ) -> Result<IndicatorResponse<I::Value>> {
+        self.send(I::ENDPOINT, &req).await
+    }
+
+    pub async fn eod(&self, req: EodRequest) -> Result<EodResponse> {
+        self.send("eod", &req).await
+    }
+
+    pub async fn earliest_timestamp(
+        &self,
+        req: EarliestTimestampRequest,
+    ) -> Result<EarliestTimestampResponse> {
+        self.send("earliest_timestamp", &req).await
+    }
+
+    pub async fn exchange_rate(&self, req: ExchangeRateRequest) -> Result<ExchangeRateResponse> {
+        self.send("exchange_rate", &req).await
+    }
+
+    pub async fn currency_conversion(
+        &self,
+        req: CurrencyConversionRequest,
+    ) -> Result<CurrencyConversionResponse> {
+        self.send("currency_conversion", &req).await
+    }
+
     pub async fn logo(&self, req: LogoRequest) -> Result<LogoResponse> {
         self.send("logo", &req).await
     }
 
+    pub async fn profile(&self, req: ProfileRequest) -> Result<ProfileResponse> {
+        self.send("profile", &req).await
+    }
+
+    pub async fn dividends(&self, req: DividendsRequest) -> Result<DividendsResponse> {
+        self.send("dividends", &req).await
+    }
+
+    pub async fn splits(&self, req: SplitsRequest) -> Result<SplitsResponse> {
+        self.send("splits", &req).await
+    }
+
+    pub async fn statistics(&self, req: StatisticsRequest) -> Result<StatisticsResponse> {
+        self.send("statistics", &req).await
+    }
+
+    pub async fn income_statement(
+        &self,
+        req: FinancialStatementRequest,
+    ) -> Result<IncomeStatementResponse> {
+        self.send("income_statement", &req).await
+    }
+
+    pub async fn balance_sheet(
+        &self,
+        req: FinancialStatementRequest,
+    ) -> Result<BalanceSheetResponse> {
+        self.send("balance_sheet", &req).await
+    }
+
+    pub async fn cash_flow(&self, req: FinancialStatementRequest) -> Result<CashFlowResponse> {
+        self.send("cash_flow", &req).await
+    }
+
+    pub async fn institutional_holders(
+        &self,
+        req: OwnershipRequest,
+    ) -> Result<InstitutionalHoldersResponse> {
+        self.send("institutional_holders", &req).await
+    }
+
+    pub async fn fund_holders(&self, req: OwnershipRequest) -> Result<FundHoldersResponse> {
+        self.send("fund_holders", &req).await
+    }
+
+    pub async fn insider_transactions(
+        &self,
+        req: OwnershipRequest,
+    ) -> Result<InsiderTransactionsResponse> {
+        self.send("insider_transactions", &req).await
+    }
+
+    pub async fn key_executives(&self, req: KeyExecutivesRequest) -> Result<KeyExecutivesResponse> {
+        self.send("key_executives", &req).await
+    }
+
+    pub async fn market_cap(&self, req: MarketCapRequest) -> Result<MarketCapResponse> {
+        self.send("market_cap", &req).await
+    }
+
+    pub async fn earnings(&self, req: EarningsRequest) -> Result<EarningsResponse> {
+        self.send("earnings", &req).await
+    }
+
+    pub async fn dividends_calendar(
+        &self,
+        req: DividendsCalendarRequest,
+    ) -> Result<DividendsCalendarResponse> {
+        let entries: Vec<_> = self.send("dividends_calendar", &req).await?;
+        Ok(group_by_date(entries, |e| e.ex_date.date()))
+    }
+
+    pub async fn splits_calendar(
+        &self,
+        req: SplitsCalendarRequest,
+    ) -> Result<SplitsCalendarResponse> {
+        let entries: Vec<_> = self.send("splits_calendar", &req).await?;
+        Ok(group_by_date(entries, |e| e.date.date()))
+    }
+
+    pub async fn earnings_calendar(
+        &self,
+        req: EarningsCalendarRequest,
+    ) -> Result<EarningsCalendarResponse> {
+        self.send("earnings_calendar", &req).await
+    }
+
+    pub async fn ipo_calendar(&self, req: IpoCalendarRequest) -> Result<IpoCalendarResponse> {
+        self.send("ipo_calendar", &req).await
+    }
+
+    pub async fn earnings_estimate(
+        &self,
+        req: AnalysisRequest,
+    ) -> Result<EarningsEstimateResponse> {
+        self.send("earnings_estimate", &req).await
+    }
+
+    pub async fn revenue_estimate(&self, req: AnalysisRequest) -> Result<RevenueEstimateResponse> {
+        self.send("revenue_estimate", &req).await
+    }
+
+    pub async fn eps_trend(&self, req: AnalysisRequest) -> Result<EpsTrendResponse> {
+        self.send("eps_trend", &req).await
+    }
+
+    pub async fn eps_revisions(&self, req: AnalysisRequest) -> Result<EpsRevisionsResponse> {
+        self.send("eps_revisions", &req).await
+    }
+
+    pub async fn growth_estimates(&self, req: AnalysisRequest) -> Result<GrowthEstimatesResponse> {
+        self.send("growth_estimates", &req).await
+    }
+
+    pub async fn recommendations(&self, req: AnalysisRequest) -> Result<RecommendationsResponse> {
+        self.send("recommendations", &req).await
+    }
+
+    pub async fn price_target(&self, req: AnalysisRequest) -> Result<PriceTargetResponse> {
+        self.send("price_target", &req).await
+    }
+
+    pub async fn analyst_ratings(
+        &self,
+        req: AnalystRatingsRequest,
+    ) -> Result<AnalystRatingsResponse> {
+        self.send("analyst_ratings/light", &req).await
+    }
+
+    pub async fn etf_summary(&self, req: FundRequest) -> Result<EtfSummaryResponse> {
+        self.send("etfs/world/summary", &req).await
+    }
+
+    pub async fn etf_performance(&self, req: FundRequest) -> Result<EtfPerformanceResponse> {
+        self.send("etfs/world/performance", &req).await
+    }
+
+    pub async fn etf_risk(&self, req: FundRequest) -> Result<EtfRiskResponse> {
+        self.send("etfs/world/risk", &req).await
+    }
+
+    pub async fn etf_composition(&self, req: FundRequest) -> Result<EtfCompositionResponse> {
+        self.send("etfs/world/composition", &req).await
+    }
+
+    pub async fn mutual_fund_summary(&self, req: FundRequest) -> Result<MutualFundSummaryResponse> {
+        self.send("mutual_funds/world/summary", &req).await
+    }
+
+    pub async fn mutual_fund_performance(
+        &self,
+        req: FundRequest,
+    ) -> Result<MutualFundPerformanceResponse> {
+        self.send("mutual_funds/world/performance", &req).await
+    }
+
+    pub async fn mutual_fund_risk(&self, req: FundRequest) -> Result<MutualFundRiskResponse> {
+        self.send("mutual_funds/world/risk", &req).await
+    }
+
+    pub async fn mutual_fund_composition(
+        &self,
+        req: FundRequest,
+    ) -> Result<MutualFundCompositionResponse> {
+        self.send("mutual_funds/world/composition", &req).await
+    }
+
+    pub async fn mutual_fund_purchase_info(
+        &self,
+        req: FundRequest,
+    ) -> Result<MutualFundPurchaseInfoResponse> {
+        self.send("mutual_funds/world/purchase_info", &req).await
+    }
+
+    pub async fn mutual_fund_sustainability(
+        &self,
+        req: FundRequest,
+    ) -> Result<MutualFundSustainabilityResponse> {
+        self.send("mutual_funds/world/sustainability", &req).await
+    }
+
+    pub async fn options_expiration(
+        &self,
+        req: OptionsExpirationRequest,
+    ) -> Result<OptionsExpirationResponse> {
+        self.send("options/expiration", &req).await
+    }
+
+    pub async fn options_chain(&self, req: OptionsChainRequest) -> Result<OptionsChainResponse> {
+        self.send("options/chain", &req).await
+    }
+
+    pub async fn edgar_filings(&self, req: EdgarFilingsRequest) -> Result<EdgarFilingsResponse> {
+        self.send("edgar_filings/archive", &req).await
+    }
+
+    pub async fn direct_holders(&self, req: RegulatoryRequest) -> Result<DirectHoldersResponse> {
+        self.send("direct_holders", &req).await
+    }
+
+    pub async fn tax_info(&self, req: RegulatoryRequest) -> Result<TaxInfoResponse> {
+        self.send("tax_info", &req).await
+    }
+
+    pub async fn stocks(&self, req: StocksRequest) -> Result<StocksResponse> {
+        self.send("stocks", &req).await
+    }
+
+    pub async fn forex_pairs(&self, req: ForexPairsRequest) -> Result<ForexPairsResponse> {
+        self.send("forex_pairs", &req).await
+    }
+
+    pub async fn cryptocurrencies(
+        &self,
+        req: CryptocurrenciesRequest,
+    ) -> Result<CryptocurrenciesResponse> {
+        self.send("cryptocurrencies", &req).await
+    }
+
+    pub async fn symbol_search(&self, req: SymbolSearchRequest) -> Result<SymbolSearchResponse> {
+        self.send("symbol_search", &req).await
+    }
+
+    pub async fn exchanges(&self, req: ExchangesRequest) -> Result<ExchangesResponse> {
+        self.send("exchanges", &req).await
+    }
+
+    pub async fn market_state(&self, req: MarketStateRequest) -> Result<MarketStateResponse> {
+        self.send("market_state", &req).await
+    }
+
     async fn send<T: serde::ser::Serialize, U: serde::de::DeserializeOwned>(
         &self,
         endpoint: &str,
         req: &T,
     ) -> Result<U> {
+        let val = self.fetch(endpoint, req).await?;
+        parse_value(val)
+    }
+
+    async fn send_batch<T: serde::ser::Serialize, U: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        req: &T,
+        symbols: &[String],
+    ) -> Result<HashMap<String, Result<U>>> {
+        let val = self.fetch(endpoint, req).await?;
+        parse_batch(val, symbols)
+    }
+
+    async fn fetch<T: serde::ser::Serialize>(
+        &self,
+        endpoint: &str,
+        req: &T,
+    ) -> Result<serde_json::Value> {
         let params = serde_urlencoded::to_string(req)?;
         let url = format!("{}/{}?{}", API_URL, endpoint, params);
 
         let res = self.client.get(&url, &self.api_key).await?;
+        parse_response(res)
+    }
+}
+
+fn parse_response(res: Response) -> Result<serde_json::Value> {
+    if res.status == 200 {
+        Ok(serde_json::from_str(&res.body)?)
+    } else {
+        Err(Error::DataError(format!("status {}", res.status)))
+    }
+}
+
+fn parse_value<U: serde::de::DeserializeOwned>(val: serde_json::Value) -> Result<U> {
+    check_status(&val)?;
+    Ok(serde_json::from_value::<U>(val)?)
+}
+
+fn parse_batch<U: serde::de::DeserializeOwned>(
+    val: serde_json::Value,
+    symbols: &[String],
+) -> Result<HashMap<String, Result<U>>> {
+    // with a single symbol, the response is not keyed by the symbol, so only
+    // the error code tells whether the request or the symbol failed
+    if symbols.len() == 1 {
+        let code = val.get("code").and_then(serde_json::Value::as_u64);
+        if code.is_some_and(|c| REQUEST_ERROR_CODES.contains(&c)) {
+            check_status(&val)?;
+        }
+
+        return Ok(HashMap::from([(symbols[0].clone(), parse_value(val))]));
+    }
+
+    // an error at the top level means the whole request failed
+    check_status(&val)?;
+
+    match val {
+        serde_json::Value::Object(values) => Ok(values
+            .into_iter()
+            .map(|(symbol, val)| (symbol, parse_value(val)))
+            .collect()),
+        _ => Err(Error::DataError(
+            "batch response is not an object keyed by symbol".into(),
+        )),
+    }
+}
 
-        if res.status == 200 {
-            let val: serde_json::Value = serde_json::from_str(&res.body)?;
-            if let Some(status) = val.get("status") {
-                if !status.is_string() {
+fn parse_complex_data(
+    mut val: serde_json::Value,
+    symbols: &[String],
+    series_per_symbol: usize,
+) -> Result<HashMap<String, Vec<Result<ComplexDataSeries>>>> {
+    check_status(&val)?;
+
+    let data = match val.get_mut("data").map(serde_json::Value::take) {
+        Some(serde_json::Value::Array(data)) => data,
+        _ => {
+            return Err(Error::DataError(
+                "complex data response does not contain the data array".into(),
+            ))
+        }
+    };
+
+    let mut series = HashMap::<String, Vec<Result<ComplexDataSeries>>>::new();
+    for (i, val) in data.into_iter().enumerate() {
+        // failed series carry no meta, so fall back to the position in the
+        // data array, which follows the order of symbols, intervals and methods
+        let symbol = match val.pointer("/meta/symbol").and_then(|s| s.as_str()) {
+            Some(symbol) => symbol.to_owned(),
+            None => match symbols.get(i / series_per_symbol.max(1)) {
+                Some(symbol) => symbol.clone(),
+                None => {
                     return Err(Error::DataError(
-                        "status value in the response is not a string".into(),
-                    ));
-                }
-                if status.as_str().unwrap() == "error" {
-                    let reason = if let Some(error_message) = val.get("message") {
-                        error_message.as_str().unwrap()
-                    } else {
-                        "<unknown reasuon>"
-                    };
-
-                    return Err(Error::DataError(reason.into()));
+                        "complex data response contains more series than requested".into(),
+                    ))
                 }
-            }
+            },
+        };
+
+        series.entry(symbol).or_default().push(parse_value(val));
+    }
 
-            Ok(serde_json::from_value::<U>(val)?)
-        } else {
-            Err(Error::DataError(format!("status {}", res.status)))
+    Ok(series)
+}
+
+fn check_status(val: &serde_json::Value) -> Result<()> {
+    if let Some(status) = val.get("status") {
+        if !status.is_string() {
+            return Err(Error::DataError(
+                "status value in the response is not a string".into(),
+            ));
+        }
+        if status.as_str().unwrap() == "error" {
+            let reason = if let Some(error_message) = val.get("message") {
+                error_message.as_str().unwrap()
+            } else {
+                "<unknown reasuon>"
+            };
+
+            return Err(Error::DataError(reason.into()));
         }
     }
+
+    Ok(())
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -245,4 +743,68 @@ mod test {
 
         assert_ok!(res);
     }
+
+    #[test]
+    pub fn batch_with_symbol_error() {
+        let response = r#"{"AAPL":{"price":"156.89999"},"XXXX":{"code":400,"message":"symbol not found","status":"error"}}"#;
+
+        let res = parse_batch::<crate::core::PriceResponse>(
+            serde_json::from_str(response).unwrap(),
+            &["AAPL".into(), "XXXX".into()],
+        );
+
+        assert_ok!(&res);
+
+        let res = res.unwrap();
+        assert_eq!(156.89999, res["AAPL"].as_ref().unwrap().price);
+        assert!(res["XXXX"].is_err());
+    }
+
+    #[test]
+    pub fn batch_with_single_symbol_error() {
+        let response = r#"{"code":400,"message":"symbol not found","status":"error"}"#;
+
+        let res = parse_batch::<crate::core::PriceResponse>(
+            serde_json::from_str(response).unwrap(),
+            &["XXXX".into()],
+        );
+
+        assert_ok!(&res);
+        assert!(res.unwrap()["XXXX"].is_err());
+
+        let response = r#"{"code":401,"message":"invalid api key","status":"error"}"#;
+
+        let res = parse_batch::<crate::core::PriceResponse>(
+            serde_json::from_str(response).unwrap(),
+            &["AAPL".into()],
+        );
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    pub fn complex_data_by_symbol() {
+        let response = r#"{"data":[{"meta":{"symbol":"AAPL","interval":"1day","currency":"USD","exchange_timezone":"America/New_York","exchange":"NASDAQ","mic_code":"XNGS","type":"Common Stock"},"values":[{"datetime":"2022-09-20","open":"153.39999","high":"158.08000","low":"153.08000","close":"156.89999","volume":"107547900"}],"status":"ok"},{"meta":{"symbol":"AAPL","interval":"1day","currency":"USD","exchange_timezone":"America/New_York","exchange":"NASDAQ","mic_code":"XNGS","type":"Common Stock","indicator":{"name":"EMA - Exponential Moving Average","series_type":"close","time_period":12}},"values":[{"datetime":"2022-09-20","ema":"155.43514"}],"status":"ok"},{"code":400,"message":"symbol not found","status":"error"},{"code":400,"message":"symbol not found","status":"error"}],"status":"ok"}"#;
+
+        let res = parse_complex_data(
+            serde_json::from_str(response).unwrap(),
+            &["AAPL".into(), "XXXX".into()],
+            2,
+        );
+
+        assert_ok!(&res);
+
+        let res = res.unwrap();
+        assert_eq!(2, res["AAPL"].len());
+        let time_series = res["AAPL"][0].as_ref().unwrap().time_series().unwrap();
+        assert_eq!(156.89999, time_series[0].close);
+        let ema = res["AAPL"][1]
+            .as_ref()
+            .unwrap()
+            .indicator::<crate::technical::Ema>()
+            .unwrap();
+        assert_eq!(155.43514, ema[0].ema);
+        assert_eq!(2, res["XXXX"].len());
+        assert!(res["XXXX"].iter().all(|s| s.is_err()));
+    }
 }
@@ -0,0 +1,126 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use derive_builder::Builder;
+use serde_derive::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::core::deserialize_td_datetime;
+use crate::fundamentals::{FundamentalsMeta, SymbolRequest};
+
+pub type OptionsExpirationRequest = SymbolRequest;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OptionsExpirationResponse {
+    pub meta: FundamentalsMeta,
+    pub dates: Vec<NaiveDate>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum OptionSide {
+    #[serde(rename = "call")]
+    Call,
+
+    #[serde(rename = "put")]
+    Put,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct OptionsChainRequest {
+    #[serde(flatten)]
+    pub instrument: SymbolRequest,
+
+    #[builder(default, setter(strip_option))]
+    pub expiration_date: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub option_id: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub side: Option<OptionSide>,
+}
+
+impl OptionsChainRequest {
+    pub fn builder() -> OptionsChainRequestBuilder {
+        OptionsChainRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OptionsChainResponse {
+    pub meta: FundamentalsMeta,
+
+    #[serde(default)]
+    pub calls: Vec<OptionContract>,
+
+    #[serde(default)]
+    pub puts: Vec<OptionContract>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OptionContract {
+    pub contract_name: String,
+    pub option_id: String,
+
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub last_trade_date: NaiveDateTime,
+    pub strike: f64,
+    pub last_price: f64,
+    pub bid: f64,
+    pub ask: f64,
+    pub change: f64,
+    pub percent_change: f64,
+
+    #[serde(default)]
+    pub volume: Option<u64>,
+    #[serde(default)]
+    pub open_interest: Option<u64>,
+    pub implied_volatility: f64,
+    pub in_the_money: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use tokio_test::assert_ok;
+
+    use super::*;
+
+    #[test]
+    pub fn test_options_chain_request() {
+        let request = OptionsChainRequest::builder()
+            .instrument(
+                SymbolRequest::builder()
+                    .symbol("AAPL".into())
+                    .build()
+                    .unwrap(),
+            )
+            .expiration_date(NaiveDate::from_ymd_opt(2024, 6, 21).unwrap())
+            .side(OptionSide::Put)
+            .build()
+            .unwrap();
+
+        let query = serde_urlencoded::to_string(&request);
+
+        assert_ok!(&query);
+
+        assert_eq!(
+            "symbol=AAPL&expiration_date=2024-06-21&side=put",
+            query.unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_options_chain_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNGS","exchange_timezone":"America/New_York"},"calls":[{"contract_name":"AAPL210820C00065000","option_id":"AAPL210820C00065000","last_trade_date":"2021-08-17 12:04:26","strike":65,"last_price":86.5,"bid":82.6,"ask":84.5,"change":0,"percent_change":0,"volume":1,"open_interest":0,"implied_volatility":3.46875,"in_the_money":true}],"puts":[{"contract_name":"AAPL210820P00065000","option_id":"AAPL210820P00065000","last_trade_date":"2021-08-16 14:35:53","strike":65,"last_price":0.01,"bid":0,"ask":0.01,"change":0,"percent_change":0,"volume":null,"open_interest":136,"implied_volatility":2.0625,"in_the_money":false}]}"#;
+
+        let response = serde_json::from_str::<OptionsChainResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(1, res.calls.len());
+        assert!(res.calls[0].in_the_money);
+        assert!(res.puts[0].volume.is_none());
+        assert_eq!(Some(136), res.puts[0].open_interest);
+    }
+}
@@ -0,0 +1,368 @@
+use std::time::Duration;
+
+use derive_builder::Builder;
+use serde_derive::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::CommonQueryParameters;
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct StocksRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    #[builder(default, setter(strip_option))]
+    pub symbol: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub show_plan: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub include_delisted: Option<bool>,
+}
+
+impl StocksRequest {
+    pub fn builder() -> StocksRequestBuilder {
+        StocksRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StocksResponse {
+    pub data: Vec<StockListing>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StockListing {
+    pub symbol: String,
+    pub name: String,
+    pub currency: String,
+    pub exchange: String,
+    pub mic_code: String,
+    pub country: String,
+
+    #[serde(rename = "type")]
+    pub instrument_type: String,
+
+    #[serde(default)]
+    pub figi_code: Option<String>,
+
+    #[serde(default)]
+    pub access: Option<AccessPlan>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccessPlan {
+    pub global: String,
+    pub plan: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct ForexPairsRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    #[builder(default, setter(strip_option))]
+    pub symbol: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub currency_base: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub currency_quote: Option<String>,
+}
+
+impl ForexPairsRequest {
+    pub fn builder() -> ForexPairsRequestBuilder {
+        ForexPairsRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ForexPairsResponse {
+    pub data: Vec<ForexPairListing>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ForexPairListing {
+    pub symbol: String,
+    pub currency_group: String,
+    pub currency_base: String,
+    pub currency_quote: String,
+
+    #[serde(default)]
+    pub available_exchanges: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct CryptocurrenciesRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    #[builder(default, setter(strip_option))]
+    pub symbol: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub currency_base: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub currency_quote: Option<String>,
+}
+
+impl CryptocurrenciesRequest {
+    pub fn builder() -> CryptocurrenciesRequestBuilder {
+        CryptocurrenciesRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CryptocurrenciesResponse {
+    pub data: Vec<CryptocurrencyListing>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CryptocurrencyListing {
+    pub symbol: String,
+    pub available_exchanges: Vec<String>,
+    pub currency_base: String,
+    pub currency_quote: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct SymbolSearchRequest {
+    pub symbol: String,
+
+    #[serde(rename = "outputsize")]
+    #[builder(default, setter(strip_option))]
+    pub output_size: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub show_plan: Option<bool>,
+}
+
+impl SymbolSearchRequest {
+    pub fn builder() -> SymbolSearchRequestBuilder {
+        SymbolSearchRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SymbolSearchResponse {
+    pub data: Vec<SymbolMatch>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SymbolMatch {
+    pub symbol: String,
+    pub instrument_name: String,
+    pub exchange: String,
+    pub mic_code: String,
+    pub exchange_timezone: String,
+    pub instrument_type: String,
+    pub country: String,
+    pub currency: String,
+
+    #[serde(default)]
+    pub access: Option<AccessPlan>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct ExchangesRequest {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    #[builder(default, setter(strip_option))]
+    pub name: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub code: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub show_plan: Option<bool>,
+}
+
+impl ExchangesRequest {
+    pub fn builder() -> ExchangesRequestBuilder {
+        ExchangesRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExchangesResponse {
+    pub data: Vec<ExchangeListing>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExchangeListing {
+    #[serde(default)]
+    pub title: Option<String>,
+    pub name: String,
+    pub code: String,
+    pub country: String,
+    pub timezone: String,
+
+    #[serde(default)]
+    pub access: Option<AccessPlan>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, Default)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct MarketStateRequest {
+    #[builder(default, setter(strip_option))]
+    pub exchange: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub code: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub country: Option<String>,
+}
+
+impl MarketStateRequest {
+    pub fn builder() -> MarketStateRequestBuilder {
+        MarketStateRequestBuilder::default()
+    }
+}
+
+pub type MarketStateResponse = Vec<MarketState>;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MarketState {
+    pub name: String,
+    pub code: String,
+    pub country: String,
+    pub is_market_open: bool,
+
+    #[serde(deserialize_with = "deserialize_td_duration")]
+    pub time_after_open: Duration,
+
+    #[serde(deserialize_with = "deserialize_td_duration")]
+    pub time_to_open: Duration,
+
+    #[serde(deserialize_with = "deserialize_td_duration")]
+    pub time_to_close: Duration,
+}
+
+pub fn deserialize_td_duration<'de, D>(d: D) -> Result<Duration, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    d.deserialize_str(TdDurationVisitor)
+}
+
+struct TdDurationVisitor;
+impl<'de> serde::de::Visitor<'de> for TdDurationVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a duration string in the TwelveData format (%H:%M:%S)"
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let mut parts = v.splitn(3, ':');
+        let mut secs = 0u64;
+        for _ in 0..3 {
+            let part = parts
+                .next()
+                .ok_or_else(|| E::custom(format!("unexpected duration format of {}", v)))?;
+            let value = part
+                .parse::<u64>()
+                .map_err(|e| E::custom(format!("unexpected duration format of {}: {}", v, e)))?;
+            secs = secs * 60 + value;
+        }
+
+        Ok(Duration::from_secs(secs))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tokio_test::assert_ok;
+
+    use super::*;
+
+    #[test]
+    pub fn test_stocks_response() {
+        let response = r#"{"data":[{"symbol":"AAPL","name":"Apple Inc","currency":"USD","exchange":"NASDAQ","mic_code":"XNGS","country":"United States","type":"Common Stock","figi_code":"BBG000B9Y5X2","access":{"global":"Basic","plan":"Basic"}},{"symbol":"AAPL","name":"Apple Inc","currency":"EUR","exchange":"FSX","mic_code":"XFRA","country":"Germany","type":"Common Stock"}],"status":"ok"}"#;
+
+        let response = serde_json::from_str::<StocksResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(2, res.data.len());
+        assert_eq!("Basic", res.data[0].access.as_ref().unwrap().plan);
+        assert!(res.data[1].access.is_none());
+    }
+
+    #[test]
+    pub fn test_cryptocurrencies_response() {
+        let response = r#"{"data":[{"symbol":"0xBTC/BTC","available_exchanges":["Hotbit"],"currency_base":"0xBitcoin","currency_quote":"Bitcoin"},{"symbol":"BTC/USD","available_exchanges":["Binance","Coinbase Pro","Kraken"],"currency_base":"Bitcoin","currency_quote":"US Dollar"}],"status":"ok"}"#;
+
+        let response = serde_json::from_str::<CryptocurrenciesResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(2, res.data.len());
+        assert_eq!(3, res.data[1].available_exchanges.len());
+    }
+
+    #[test]
+    pub fn test_symbol_search_response() {
+        let response = r#"{"data":[{"symbol":"AA","instrument_name":"Alcoa Corp","exchange":"NYSE","mic_code":"XNYS","exchange_timezone":"America/New_York","instrument_type":"Common Stock","country":"United States","currency":"USD"},{"symbol":"AAL","instrument_name":"American Airlines Group Inc","exchange":"NASDAQ","mic_code":"XNGS","exchange_timezone":"America/New_York","instrument_type":"Common Stock","country":"United States","currency":"USD"}],"status":"ok"}"#;
+
+        let response = serde_json::from_str::<SymbolSearchResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(2, res.data.len());
+        assert_eq!("Alcoa Corp", res.data[0].instrument_name);
+    }
+
+    #[test]
+    pub fn test_market_state_response() {
+        let response = r#"[{"name":"NYSE","code":"XNYS","country":"United States","is_market_open":true,"time_after_open":"02:39:03","time_to_open":"00:00:00","time_to_close":"04:20:57"},{"name":"XETR","code":"XETR","country":"Germany","is_market_open":false,"time_after_open":"00:00:00","time_to_open":"62:30:00","time_to_close":"00:00:00"}]"#;
+
+        let response = serde_json::from_str::<MarketStateResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(
+            Duration::from_secs(2 * 3600 + 39 * 60 + 3),
+            res[0].time_after_open
+        );
+        assert_eq!(
+            Duration::from_secs(62 * 3600 + 30 * 60),
+            res[1].time_to_open
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use derive_builder::Builder;
+use serde_derive::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::fundamentals::{Holder, SymbolRequest};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegulatoryMeta {
+    pub symbol: String,
+
+    #[serde(default)]
+    pub name: Option<String>,
+    pub exchange: String,
+    pub mic_code: String,
+    pub exchange_timezone: String,
+
+    #[serde(rename = "type", default)]
+    pub instrument_type: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct EdgarFilingsRequest {
+    #[serde(flatten)]
+    pub instrument: SymbolRequest,
+
+    #[builder(default, setter(strip_option))]
+    pub form_type: Option<String>,
+
+    #[serde(rename = "filled_from")]
+    #[builder(default, setter(strip_option))]
+    pub filed_from: Option<NaiveDate>,
+
+    #[serde(rename = "filled_to")]
+    #[builder(default, setter(strip_option))]
+    pub filed_to: Option<NaiveDate>,
+
+    #[builder(default, setter(strip_option))]
+    pub page: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub page_size: Option<u16>,
+}
+
+impl EdgarFilingsRequest {
+    pub fn builder() -> EdgarFilingsRequestBuilder {
+        EdgarFilingsRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EdgarFilingsResponse {
+    pub meta: RegulatoryMeta,
+    pub values: Vec<EdgarFiling>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EdgarFiling {
+    #[serde(default)]
+    pub cik: Option<u64>,
+    pub form_type: String,
+    pub filed_at: i64,
+    pub filing_url: String,
+
+    #[serde(default)]
+    pub files: Vec<EdgarFile>,
+}
+
+impl EdgarFiling {
+    pub fn filed_date(&self) -> Option<NaiveDateTime> {
+        DateTime::from_timestamp(self.filed_at, 0).map(|d| d.naive_utc())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EdgarFile {
+    pub name: String,
+
+    #[serde(rename = "type", default)]
+    pub file_type: Option<String>,
+
+    #[serde(default)]
+    pub size: Option<u64>,
+    pub url: String,
+}
+
+pub type RegulatoryRequest = SymbolRequest;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DirectHoldersResponse {
+    pub meta: RegulatoryMeta,
+    pub direct_holders: Vec<Holder>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaxInfoResponse {
+    pub meta: RegulatoryMeta,
+    pub data: TaxInfo,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaxInfo {
+    #[serde(default)]
+    pub tax_indicator: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use tokio_test::assert_ok;
+
+    use super::*;
+
+    #[test]
+    pub fn test_edgar_filings_request() {
+        let request = EdgarFilingsRequest::builder()
+            .instrument(
+                SymbolRequest::builder()
+                    .symbol("AAPL".into())
+                    .build()
+                    .unwrap(),
+            )
+            .form_type("10-K".into())
+            .filed_from(NaiveDate::from_ymd_opt(2022, 1, 1).unwrap())
+            .filed_to(NaiveDate::from_ymd_opt(2022, 12, 31).unwrap())
+            .build()
+            .unwrap();
+
+        let query = serde_urlencoded::to_string(&request);
+
+        assert_ok!(&query);
+
+        assert_eq!(
+            "symbol=AAPL&form_type=10-K&filled_from=2022-01-01&filled_to=2022-12-31",
+            query.unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_edgar_filings_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","exchange":"NASDAQ","mic_code":"XNAS","exchange_timezone":"America/New_York","type":"Common Stock"},"values":[{"cik":320193,"filed_at":1714685445,"form_type":"10-Q","filing_url":"https://www.sec.gov/Archives/edgar/data/320193/000032019324000069/0000320193-24-000069-index.htm","files":[{"name":"aapl-20240330.htm","type":"10-Q","size":2157614,"url":"https://www.sec.gov/Archives/edgar/data/320193/000032019324000069/aapl-20240330.htm"}]}]}"#;
+
+        let response = serde_json::from_str::<EdgarFilingsResponse>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        let filing = &res.values[0];
+        assert_eq!("10-Q", filing.form_type);
+        assert_eq!(
+            Some(NaiveDate::from_ymd_opt(2024, 5, 2).unwrap()),
+            filing.filed_date().map(|d| d.date())
+        );
+        assert_eq!(1, filing.files.len());
+    }
+}
@@ -0,0 +1,647 @@
+use chrono::NaiveDateTime;
+use derive_builder::Builder;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_derive::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr};
+
+use super::{CommonQueryParameters, Interval, Order};
+use crate::core::deserialize_td_datetime;
+
+pub trait TechnicalIndicator: Serialize {
+    const ENDPOINT: &'static str;
+
+    type Value: DeserializeOwned;
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct IndicatorRequest<I> {
+    #[serde(flatten)]
+    #[builder(default)]
+    pub common: CommonQueryParameters,
+
+    pub symbol: String,
+    pub interval: Interval,
+
+    #[serde(flatten)]
+    pub indicator: I,
+
+    #[serde(rename = "outputsize")]
+    #[builder(default, setter(strip_option))]
+    pub output_size: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub order: Option<Order>,
+
+    #[builder(default, setter(strip_option))]
+    pub start_date: Option<NaiveDateTime>,
+
+    #[builder(default, setter(strip_option))]
+    pub end_date: Option<NaiveDateTime>,
+}
+
+impl<I> IndicatorRequest<I> {
+    pub fn builder() -> IndicatorRequestBuilder<I> {
+        IndicatorRequestBuilder::default()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndicatorResponse<V> {
+    pub meta: IndicatorMeta,
+    pub values: Vec<V>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndicatorMeta {
+    pub symbol: String,
+    pub interval: Interval,
+
+    #[serde(default)]
+    pub currency: Option<String>,
+    pub exchange_timezone: String,
+    pub exchange: String,
+    pub mic_code: String,
+
+    #[serde(rename = "type")]
+    pub instrument_type: String,
+    pub indicator: IndicatorInfo,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndicatorInfo {
+    pub name: String,
+
+    #[serde(flatten)]
+    pub parameters: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SeriesType {
+    #[serde(rename = "open")]
+    Open,
+
+    #[serde(rename = "high")]
+    High,
+
+    #[serde(rename = "low")]
+    Low,
+
+    #[serde(rename = "close")]
+    Close,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum MaType {
+    SMA,
+    EMA,
+    WMA,
+    DEMA,
+    TEMA,
+    TRIMA,
+    KAMA,
+    MAMA,
+    T3MA,
+}
+
+/// Defines an indicator whose values carry a single output named after the
+/// given field, along with its builder and `TechnicalIndicator` impl.
+macro_rules! indicator {
+    ($name:ident, $builder:ident, $value:ident, $endpoint:literal => $output:ident {
+        $($param:ident: $param_type:ty,)*
+    }) => {
+        #[derive(Debug, Serialize, Deserialize, Builder, Default)]
+        #[builder(pattern = "owned")]
+        #[skip_serializing_none]
+        pub struct $name {
+            $(
+                #[builder(default, setter(strip_option))]
+                pub $param: Option<$param_type>,
+            )*
+        }
+
+        impl $name {
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+        }
+
+        #[serde_as]
+        #[derive(Debug, Serialize, Deserialize)]
+        pub struct $value {
+            #[serde(deserialize_with = "deserialize_td_datetime")]
+            pub datetime: NaiveDateTime,
+
+            #[serde_as(as = "DisplayFromStr")]
+            pub $output: f64,
+        }
+
+        impl TechnicalIndicator for $name {
+            const ENDPOINT: &'static str = $endpoint;
+
+            type Value = $value;
+        }
+    };
+}
+
+indicator!(Sma, SmaBuilder, SmaValue, "sma" => sma {
+    series_type: SeriesType,
+    time_period: u16,
+});
+
+indicator!(Ema, EmaBuilder, EmaValue, "ema" => ema {
+    series_type: SeriesType,
+    time_period: u16,
+});
+
+indicator!(Wma, WmaBuilder, WmaValue, "wma" => wma {
+    series_type: SeriesType,
+    time_period: u16,
+});
+
+indicator!(Dema, DemaBuilder, DemaValue, "dema" => dema {
+    series_type: SeriesType,
+    time_period: u16,
+});
+
+indicator!(Tema, TemaBuilder, TemaValue, "tema" => tema {
+    series_type: SeriesType,
+    time_period: u16,
+});
+
+indicator!(Trima, TrimaBuilder, TrimaValue, "trima" => trima {
+    series_type: SeriesType,
+    time_period: u16,
+});
+
+indicator!(Kama, KamaBuilder, KamaValue, "kama" => kama {
+    series_type: SeriesType,
+    time_period: u16,
+});
+
+indicator!(Ma, MaBuilder, MaValue, "ma" => ma {
+    series_type: SeriesType,
+    time_period: u16,
+    ma_type: MaType,
+});
+
+#[derive(Debug, Serialize, Deserialize, Builder, Default)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct Mama {
+    #[builder(default, setter(strip_option))]
+    pub series_type: Option<SeriesType>,
+
+    #[builder(default, setter(strip_option))]
+    pub fast_limit: Option<f64>,
+
+    #[builder(default, setter(strip_option))]
+    pub slow_limit: Option<f64>,
+}
+
+impl Mama {
+    pub fn builder() -> MamaBuilder {
+        MamaBuilder::default()
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MamaValue {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub datetime: NaiveDateTime,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub mama: f64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub fama: f64,
+}
+
+impl TechnicalIndicator for Mama {
+    const ENDPOINT: &'static str = "mama";
+
+    type Value = MamaValue;
+}
+
+indicator!(T3, T3Builder, T3Value, "t3ma" => t3ma {
+    series_type: SeriesType,
+    time_period: u16,
+    v_factor: f64,
+});
+
+#[derive(Debug, Serialize, Deserialize, Builder, Default)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct Vwap {
+    #[builder(default, setter(strip_option))]
+    pub sd: Option<f64>,
+
+    #[builder(default, setter(strip_option))]
+    pub sd_time_period: Option<u16>,
+}
+
+impl Vwap {
+    pub fn builder() -> VwapBuilder {
+        VwapBuilder::default()
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VwapValue {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub datetime: NaiveDateTime,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub vwap: f64,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub upper_band: Option<f64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub lower_band: Option<f64>,
+}
+
+impl TechnicalIndicator for Vwap {
+    const ENDPOINT: &'static str = "vwap";
+
+    type Value = VwapValue;
+}
+
+indicator!(Rsi, RsiBuilder, RsiValue, "rsi" => rsi {
+    series_type: SeriesType,
+    time_period: u16,
+});
+
+#[derive(Debug, Serialize, Deserialize, Builder, Default)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct Macd {
+    #[builder(default, setter(strip_option))]
+    pub series_type: Option<SeriesType>,
+
+    #[builder(default, setter(strip_option))]
+    pub fast_period: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub slow_period: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub signal_period: Option<u16>,
+}
+
+impl Macd {
+    pub fn builder() -> MacdBuilder {
+        MacdBuilder::default()
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MacdValue {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub datetime: NaiveDateTime,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub macd: f64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub macd_signal: f64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub macd_hist: f64,
+}
+
+impl TechnicalIndicator for Macd {
+    const ENDPOINT: &'static str = "macd";
+
+    type Value = MacdValue;
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, Default)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct Stoch {
+    #[builder(default, setter(strip_option))]
+    pub fast_k_period: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub slow_k_period: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub slow_d_period: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub slow_kma_type: Option<MaType>,
+
+    #[builder(default, setter(strip_option))]
+    pub slow_dma_type: Option<MaType>,
+}
+
+impl Stoch {
+    pub fn builder() -> StochBuilder {
+        StochBuilder::default()
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StochValue {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub datetime: NaiveDateTime,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub slow_k: f64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub slow_d: f64,
+}
+
+impl TechnicalIndicator for Stoch {
+    const ENDPOINT: &'static str = "stoch";
+
+    type Value = StochValue;
+}
+
+#[derive(Debug, Serialize, Deserialize, Builder, Default)]
+#[builder(pattern = "owned")]
+#[skip_serializing_none]
+pub struct StochRsi {
+    #[builder(default, setter(strip_option))]
+    pub series_type: Option<SeriesType>,
+
+    #[builder(default, setter(strip_option))]
+    pub rsi_length: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub stoch_length: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub k_period: Option<u16>,
+
+    #[builder(default, setter(strip_option))]
+    pub d_period: Option<u16>,
+}
+
+impl StochRsi {
+    pub fn builder() -> StochRsiBuilder {
+        StochRsiBuilder::default()
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StochRsiValue {
+    #[serde(deserialize_with = "deserialize_td_datetime")]
+    pub datetime: NaiveDateTime,
+
+    #[serde_as(as = "DisplayFromStr")]
+    pub k: f64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub d: f64,
+}
+
+impl TechnicalIndicator for StochRsi {
+    const ENDPOINT: &'static str = "stochrsi";
+
+    type Value = StochRsiValue;
+}
+
+indicator!(Adx, AdxBuilder, AdxValue, "adx" => adx {
+    time_period: u16,
+});
+
+indicator!(Adxr, AdxrBuilder, AdxrValue, "adxr" => adxr {
+    time_period: u16,
+});
+
+indicator!(Dx, DxBuilder, DxValue, "dx" => dx {
+    time_period: u16,
+});
+
+indicator!(PlusDi, PlusDiBuilder, PlusDiValue, "plus_di" => plus_di {
+    time_period: u16,
+});
+
+indicator!(MinusDi, MinusDiBuilder, MinusDiValue, "minus_di" => minus_di {
+    time_period: u16,
+});
+
+indicator!(Cci, CciBuilder, CciValue, "cci" => cci {
+    time_period: u16,
+});
+
+indicator!(WillR, WillRBuilder, WillRValue, "willr" => willr {
+    time_period: u16,
+});
+
+indicator!(Mom, MomBuilder, MomValue, "mom" => mom {
+    series_type: SeriesType,
+    time_period: u16,
+});
+
+indicator!(Roc, RocBuilder, RocValue, "roc" => roc {
+    series_type: SeriesType,
+    time_period: u16,
+});
+
+indicator!(Mfi, MfiBuilder, MfiValue, "mfi" => mfi {
+    time_period: u16,
+});
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+    use tokio_test::assert_ok;
+
+    use super::*;
+
+    #[derive(Debug, Serialize)]
+    struct Avg {
+        time_period: u16,
+    }
+
+    #[serde_as]
+    #[derive(Debug, Deserialize)]
+    struct AvgValue {
+        #[serde_as(as = "DisplayFromStr")]
+        avg: f64,
+    }
+
+    impl TechnicalIndicator for Avg {
+        const ENDPOINT: &'static str = "avg";
+
+        type Value = AvgValue;
+    }
+
+    #[test]
+    pub fn test_indicator_request() {
+        let request = IndicatorRequest::builder()
+            .symbol("AAPL".into())
+            .interval(Interval::Day)
+            .indicator(Avg { time_period: 9 })
+            .output_size(30)
+            .build()
+            .unwrap();
+
+        let query = serde_urlencoded::to_string(&request);
+
+        assert_ok!(&query);
+
+        assert_eq!(
+            "symbol=AAPL&interval=1day&time_period=9&outputsize=30",
+            query.unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_indicator_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","interval":"1day","currency":"USD","exchange_timezone":"America/New_York","exchange":"NASDAQ","mic_code":"XNGS","type":"Common Stock","indicator":{"name":"AVG - Average","series_type":"close","time_period":9}},"values":[{"datetime":"2022-09-20","avg":"153.41000"},{"datetime":"2022-09-19","avg":"153.99445"}],"status":"ok"}"#;
+
+        let response =
+            serde_json::from_str::<IndicatorResponse<<Avg as TechnicalIndicator>::Value>>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!("AVG - Average", res.meta.indicator.name);
+        assert_eq!(
+            Some(&9.into()),
+            res.meta.indicator.parameters.get("time_period")
+        );
+        assert_eq!(153.41, res.values[0].avg);
+    }
+
+    #[test]
+    pub fn test_sma_request() {
+        let request = IndicatorRequest::builder()
+            .symbol("AAPL".into())
+            .interval(Interval::Hour)
+            .indicator(
+                Sma::builder()
+                    .series_type(SeriesType::High)
+                    .time_period(20)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let query = serde_urlencoded::to_string(&request);
+
+        assert_ok!(&query);
+
+        assert_eq!(
+            "symbol=AAPL&interval=1h&series_type=high&time_period=20",
+            query.unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_ma_request() {
+        let request = IndicatorRequest::builder()
+            .symbol("AAPL".into())
+            .interval(Interval::Day)
+            .indicator(
+                Ma::builder()
+                    .time_period(9)
+                    .ma_type(MaType::EMA)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let query = serde_urlencoded::to_string(&request);
+
+        assert_ok!(&query);
+
+        assert_eq!(
+            "symbol=AAPL&interval=1day&time_period=9&ma_type=EMA",
+            query.unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_mama_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","interval":"1day","currency":"USD","exchange_timezone":"America/New_York","exchange":"NASDAQ","mic_code":"XNGS","type":"Common Stock","indicator":{"name":"MAMA - MESA Adaptive Moving Average","series_type":"close","fast_limit":0.5,"slow_limit":0.05}},"values":[{"datetime":"2022-09-20","mama":"153.85218","fama":"157.27311"}],"status":"ok"}"#;
+
+        let response = serde_json::from_str::<IndicatorResponse<MamaValue>>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2022, 9, 20)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+            res.values[0].datetime
+        );
+        assert_eq!(157.27311, res.values[0].fama);
+    }
+
+    #[test]
+    pub fn test_vwap_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","interval":"1h","currency":"USD","exchange_timezone":"America/New_York","exchange":"NASDAQ","mic_code":"XNGS","type":"Common Stock","indicator":{"name":"VWAP - Volume Weighted Average Price"}},"values":[{"datetime":"2022-09-20 15:30:00","vwap":"156.10983"}],"status":"ok"}"#;
+
+        let response = serde_json::from_str::<IndicatorResponse<VwapValue>>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(156.10983, res.values[0].vwap);
+        assert!(res.values[0].upper_band.is_none());
+    }
+
+    #[test]
+    pub fn test_macd_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","interval":"1day","currency":"USD","exchange_timezone":"America/New_York","exchange":"NASDAQ","mic_code":"XNGS","type":"Common Stock","indicator":{"name":"MACD - Moving Average Convergence Divergence","series_type":"close","fast_period":12,"slow_period":26,"signal_period":9}},"values":[{"datetime":"2022-09-20","macd":"-1.79218","macd_signal":"-0.60341","macd_hist":"-1.18877"}],"status":"ok"}"#;
+
+        let response = serde_json::from_str::<IndicatorResponse<MacdValue>>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!(-1.79218, res.values[0].macd);
+        assert_eq!(-0.60341, res.values[0].macd_signal);
+        assert_eq!(-1.18877, res.values[0].macd_hist);
+    }
+
+    #[test]
+    pub fn test_stoch_request() {
+        let request = IndicatorRequest::builder()
+            .symbol("AAPL".into())
+            .interval(Interval::Day)
+            .indicator(
+                Stoch::builder()
+                    .slow_k_period(3)
+                    .slow_kma_type(MaType::EMA)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let query = serde_urlencoded::to_string(&request);
+
+        assert_ok!(&query);
+
+        assert_eq!(
+            "symbol=AAPL&interval=1day&slow_k_period=3&slow_kma_type=EMA",
+            query.unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_plus_di_response() {
+        let response = r#"{"meta":{"symbol":"AAPL","interval":"1day","currency":"USD","exchange_timezone":"America/New_York","exchange":"NASDAQ","mic_code":"XNGS","type":"Common Stock","indicator":{"name":"PLUS_DI - Plus Directional Indicator","time_period":9}},"values":[{"datetime":"2022-09-20","plus_di":"20.34539"}],"status":"ok"}"#;
+
+        let response = serde_json::from_str::<IndicatorResponse<PlusDiValue>>(response);
+
+        assert_ok!(&response);
+
+        let res = response.unwrap();
+        assert_eq!("plus_di", PlusDi::ENDPOINT);
+        assert_eq!(20.34539, res.values[0].plus_di);
+    }
+}